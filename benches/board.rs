@@ -4,6 +4,7 @@ extern crate play_2048;
 
 use criterion::Criterion;
 use play_2048::board::{Board, Direction};
+use play_2048::tile_scheme::{merge_equal_tiles, MovesTables};
 
 fn move_left(c: &mut Criterion) {
     #[rustfmt::skip]
@@ -55,5 +56,79 @@ fn move_down(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, move_left, move_right, move_up, move_down);
+fn move_by_value_vs_in_place(c: &mut Criterion) {
+    #[rustfmt::skip]
+    let board = Board::from(vec![
+        8, 8, 0, 8,
+        8, 0, 8, 8,
+        0, 8, 8, 0,
+        8, 8, 0, 0,
+    ]);
+    c.bench_function("Move all directions by value", move |b| {
+        b.iter(|| {
+            let mut moved = board;
+            for direction in Direction::all() {
+                let new_board = moved.move_to(*direction);
+                let _has_moved = new_board != moved;
+                moved = new_board;
+            }
+            moved
+        })
+    });
+    c.bench_function("Move all directions in place", move |b| {
+        b.iter(|| {
+            let mut moved = board;
+            for direction in Direction::all() {
+                let _has_moved = moved.move_to_in_place(*direction);
+            }
+            moved
+        })
+    });
+}
+
+fn is_full(c: &mut Criterion) {
+    #[rustfmt::skip]
+    let board = Board::from(vec![
+        8, 8, 2, 8,
+        8, 4, 8, 8,
+        2, 8, 8, 16,
+        8, 8, 32, 0,
+    ]);
+    c.bench_function("Is full", move |b| b.iter(|| board.is_full()));
+    c.bench_function("Count empty tiles is 0", move |b| {
+        b.iter(|| board.count_empty_tiles() == 0)
+    });
+}
+
+/// Without the `static-tables` feature, the first move of a process builds the moves tables,
+/// which is what the first benchmark measures. With it, the first move is a plain table lookup.
+fn first_move(c: &mut Criterion) {
+    #[rustfmt::skip]
+    let board = Board::from(vec![
+        8, 8, 0, 8,
+        8, 0, 8, 8,
+        0, 8, 8, 0,
+        8, 8, 0, 0,
+    ]);
+    c.bench_function("First move with tables built at runtime", move |b| {
+        b.iter(|| {
+            let tables = MovesTables::with_merge_rule(&merge_equal_tiles);
+            board.move_with_tables(Direction::Left, &tables)
+        })
+    });
+    c.bench_function("First move with static tables", move |b| {
+        b.iter(|| board.move_to(Direction::Left))
+    });
+}
+
+criterion_group!(
+    benches,
+    move_left,
+    move_right,
+    move_up,
+    move_down,
+    move_by_value_vs_in_place,
+    is_full,
+    first_move
+);
 criterion_main!(benches);
@@ -0,0 +1,96 @@
+#[macro_use]
+extern crate criterion;
+extern crate play_2048;
+
+use criterion::Criterion;
+use play_2048::board::Board;
+use play_2048::evaluators::*;
+
+fn build_boards() -> Vec<Board> {
+    (0..10_000u64)
+        .map(|i| {
+            (0..16).fold(Board::default(), |board, tile_idx| {
+                board.set_value_by_exponent(tile_idx, (i * 7 + tile_idx as u64 * 13) % 12)
+            })
+        })
+        .collect()
+}
+
+fn build_evaluator() -> Box<dyn BoardEvaluator> {
+    Box::new(PrecomputedBoardEvaluator::new(MonotonicityEvaluator {
+        gameover_penalty: -300.,
+        monotonicity_power: 2,
+    }))
+}
+
+fn evaluate_one_by_one(c: &mut Criterion) {
+    let boards = build_boards();
+    let evaluator = build_evaluator();
+    c.bench_function("Evaluate boards one by one", move |b| {
+        b.iter(|| {
+            boards
+                .iter()
+                .map(|board| evaluator.evaluate(*board))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+fn evaluate_batch(c: &mut Criterion) {
+    let boards = build_boards();
+    let evaluator = build_evaluator();
+    c.bench_function("Evaluate boards in batch", move |b| {
+        b.iter(|| evaluator.evaluate_batch(&boards))
+    });
+}
+
+fn build_combined_evaluator() -> CombinedBoardEvaluator {
+    CombinedBoardEvaluator::default()
+        .combine(
+            MonotonicityEvaluator {
+                gameover_penalty: -300.,
+                monotonicity_power: 2,
+            },
+            1.0,
+        )
+        .combine(
+            EmptyTileEvaluator {
+                gameover_penalty: 0.,
+                power: 2,
+            },
+            200.0,
+        )
+        .combine(
+            AlignmentEvaluator {
+                gameover_penalty: 0.,
+                power: 2,
+            },
+            500.0,
+        )
+}
+
+fn evaluate_combined(c: &mut Criterion) {
+    let boards = build_boards();
+    let evaluator = build_combined_evaluator();
+    c.bench_function("Evaluate boards with combined evaluator", move |b| {
+        b.iter(|| evaluator.evaluate_batch(&boards))
+    });
+}
+
+fn evaluate_precomputed_combined(c: &mut Criterion) {
+    let boards = build_boards();
+    let evaluator = build_combined_evaluator().precompute();
+    c.bench_function(
+        "Evaluate boards with precomputed combined evaluator",
+        move |b| b.iter(|| evaluator.evaluate_batch(&boards)),
+    );
+}
+
+criterion_group!(
+    benches,
+    evaluate_one_by_one,
+    evaluate_batch,
+    evaluate_combined,
+    evaluate_precomputed_combined
+);
+criterion_main!(benches);
@@ -5,10 +5,20 @@ extern crate play_2048;
 use criterion::Criterion;
 use play_2048::board::Board;
 use play_2048::evaluators::*;
-use play_2048::solver::SolverBuilder;
+use play_2048::solver::{Solver, SolverBuilder};
 
 fn next_best_move(c: &mut Criterion) {
-    let mut solver = SolverBuilder::default()
+    let solver = build_solver(false);
+    bench_next_best_move(c, "Compute next best move", solver);
+}
+
+fn next_best_move_with_early_chance_cutoff(c: &mut Criterion) {
+    let solver = build_solver(true);
+    bench_next_best_move(c, "Compute next best move with early chance cutoff", solver);
+}
+
+fn build_solver(early_chance_cutoff: bool) -> Solver {
+    SolverBuilder::default()
         .board_evaluator(PrecomputedBoardEvaluator::new(
             CombinedBoardEvaluator::default()
                 .combine(
@@ -36,8 +46,11 @@ fn next_best_move(c: &mut Criterion) {
         .proba_4(0.1)
         .base_max_search_depth(4)
         .min_branch_proba(0.0001)
-        .build();
+        .early_chance_cutoff(early_chance_cutoff)
+        .build()
+}
 
+fn bench_next_best_move(c: &mut Criterion, name: &str, mut solver: Solver) {
     #[rustfmt::skip]
     let board = Board::from(vec![
         128, 256, 512, 2048,
@@ -45,10 +58,18 @@ fn next_best_move(c: &mut Criterion) {
         16, 4, 8, 4,
         4, 4, 8, 4,
     ]);
-    c.bench_function("Compute next best move", move |b| {
-        b.iter(|| solver.next_best_move(board))
+    // the transposition table is cleared so that each iteration performs a full search
+    c.bench_function(name, move |b| {
+        b.iter(|| {
+            solver.reset();
+            solver.next_best_move(board)
+        })
     });
 }
 
-criterion_group!(benches, next_best_move,);
+criterion_group!(
+    benches,
+    next_best_move,
+    next_best_move_with_early_chance_cutoff
+);
 criterion_main!(benches);
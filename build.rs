@@ -0,0 +1,53 @@
+// `utils` takes `Vec` from `alloc`, as the library can be built without `std`
+extern crate alloc;
+
+use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/utils.rs"]
+mod utils;
+
+/// Generates the rows tables as static arrays when the `static-tables` feature is enabled, so
+/// that they are embedded in the binary instead of being built at runtime
+/// Without the `std` feature, the tables cannot be built lazily and are always generated.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/utils.rs");
+    if env::var_os("CARGO_FEATURE_STATIC_TABLES").is_none()
+        && env::var_os("CARGO_FEATURE_STD").is_some()
+    {
+        return;
+    }
+    let out_path = Path::new(&env::var_os("OUT_DIR").unwrap()).join("rows_tables.rs");
+    let mut out = BufWriter::new(File::create(out_path).unwrap());
+    write_table(
+        &mut out,
+        "LEFT_MOVES_TABLE",
+        &utils::build_left_moves_table(),
+    );
+    write_table(
+        &mut out,
+        "RIGHT_MOVES_TABLE",
+        &utils::build_right_moves_table(),
+    );
+    write_table(&mut out, "SCORES_TABLE", &utils::build_scores_table());
+    write_table(
+        &mut out,
+        "MERGE_COUNTS_TABLE",
+        &utils::build_merge_counts_table(),
+    );
+}
+
+fn write_table<T: Display>(out: &mut impl Write, name: &str, table: &[T]) {
+    let type_name = std::any::type_name::<T>();
+    writeln!(out, "static {}: [{}; {}] = [", name, type_name, table.len()).unwrap();
+    for chunk in table.chunks(16) {
+        let values: Vec<_> = chunk.iter().map(|value| value.to_string()).collect();
+        writeln!(out, "    {},", values.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
@@ -0,0 +1,29 @@
+//! Smoke test of the `play-2048` library without `std`, which is built by the CI for a target
+//! without `std`
+#![no_std]
+
+use play_2048::board::{Board, Direction};
+use play_2048::solver::SolverBuilder;
+
+/// Returns the move found by a shallow search on a board with two tiles which can be merged
+pub fn first_move() -> Option<Direction> {
+    let board = Board::default().set_value(0, 2).set_value(4, 2);
+    let mut solver = SolverBuilder::default().base_max_search_depth(1).build();
+    solver.next_best_move(board)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_move() {
+        // When
+        let direction = first_move();
+
+        // Then
+        let board = Board::default().set_value(0, 2).set_value(4, 2);
+        let direction = direction.unwrap();
+        assert_ne!(board, board.move_to(direction));
+    }
+}
@@ -1,6 +1,22 @@
-use crate::utils::{build_left_moves_table, build_right_moves_table, get_exponent};
+use crate::error::{Error, ErrorKind};
+use crate::tile_scheme::{MovesTables, TileScheme};
+#[cfg(all(feature = "std", not(feature = "static-tables")))]
+use crate::utils::{
+    build_left_moves_table, build_merge_counts_table, build_right_moves_table, build_scores_table,
+};
+use crate::utils::{get_exponent, try_get_exponent};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display, Formatter};
+use core::iter::FromIterator;
+use core::ops::Index;
+use core::str::FromStr;
+#[cfg(all(feature = "std", not(feature = "static-tables")))]
 use lazy_static::lazy_static;
-use std::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "cli")]
 use termion::color;
 
 /// `Board` is the main object of the 2048 game. It represents the state of the 16 tiles.
@@ -23,6 +39,7 @@ pub struct Board {
 
 /// The four directions in which the tiles can be moved
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Left,
     Right,
@@ -39,14 +56,90 @@ impl Direction {
             Direction::Down,
         ]
     }
+
+    /// Returns the opposite direction
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+}
+
+/// Parses a direction from its name or its first letter, ignoring the case
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "left" | "l" => Ok(Direction::Left),
+            "right" | "r" => Ok(Direction::Right),
+            "up" | "u" => Ok(Direction::Up),
+            "down" | "d" => Ok(Direction::Down),
+            _ => Err(Error::new(
+                ErrorKind::InvalidDirection,
+                format!("Invalid direction '{}'", s),
+            )),
+        }
+    }
 }
 
+// rows tables generated by the build script, which cannot be built lazily without `std`
+#[cfg(any(feature = "static-tables", not(feature = "std")))]
+include!(concat!(env!("OUT_DIR"), "/rows_tables.rs"));
+
+#[cfg(all(feature = "std", not(feature = "static-tables")))]
 lazy_static! {
     static ref LEFT_MOVES_TABLE: Vec<u16> = build_left_moves_table();
     static ref RIGHT_MOVES_TABLE: Vec<u16> = build_right_moves_table();
+    static ref SCORES_TABLE: Vec<u32> = build_scores_table();
+    static ref MERGE_COUNTS_TABLE: Vec<u8> = build_merge_counts_table();
+}
+
+/// Returns the left and right moves tables
+#[cfg(any(feature = "static-tables", not(feature = "std")))]
+fn moves_tables() -> (&'static [u16; 65536], &'static [u16; 65536]) {
+    (&LEFT_MOVES_TABLE, &RIGHT_MOVES_TABLE)
+}
+
+/// Returns the left and right moves tables
+#[cfg(all(feature = "std", not(feature = "static-tables")))]
+fn moves_tables() -> (&'static Vec<u16>, &'static Vec<u16>) {
+    (&LEFT_MOVES_TABLE, &RIGHT_MOVES_TABLE)
 }
 
 impl Board {
+    /// Builds a board from its packed `u64` representation, as returned by `as_raw`
+    /// Each tile is stored on 4 bits as the exponent of its value, the first tile being in the
+    /// most significant bits, see `Board`.
+    pub fn from_raw(state: u64) -> Self {
+        Self { state }
+    }
+
+    /// Returns the packed `u64` representation of the board, see `from_raw`
+    pub fn as_raw(self) -> u64 {
+        self.state
+    }
+
+    /// Builds a board from the exponents of its 16 tiles, `0` representing an empty tile
+    /// This panics when an exponent is not lower than 16.
+    pub fn from_exponents(exponents: [u8; 16]) -> Self {
+        let state = exponents.iter().fold(0, |state, exponent| {
+            assert!(*exponent < 16, "Invalid exponent {}", exponent);
+            (state << 4) | *exponent as u64
+        });
+        Self { state }
+    }
+
+    /// Converts 16 tile values into a `Board`, `0` being used for empty tiles
+    /// This is the fallible alternative to `Board::from(Vec<u16>)`, which makes
+    /// `TryFrom<Vec<u16>>` unavailable.
+    pub fn try_from_vec(tiles: Vec<u16>) -> Result<Self, Error> {
+        Self::try_from(tiles.as_slice())
+    }
+
     /// Returns the value at the corresponding index
     /// The underlying vector representation is used here
     pub fn get_value(self, tile_idx: u8) -> u16 {
@@ -96,23 +189,124 @@ impl Board {
         self.transpose().rows()
     }
 
+    /// Replaces the row at index `row_idx` with `row`, encoded like the values returned by `rows`
+    pub fn set_row(self, row_idx: u8, row: u16) -> Self {
+        let bits_shift = 16 * (3 - row_idx) as u64;
+        let clear_mask = !(0xFFFF << bits_shift);
+        Board {
+            state: (self.state & clear_mask) | ((row as u64) << bits_shift),
+        }
+    }
+
+    /// Replaces the column at index `col_idx` with `column`, encoded like the values returned by
+    /// `columns`
+    pub fn set_column(self, col_idx: u8, column: u16) -> Self {
+        self.transpose().set_row(col_idx, column).transpose()
+    }
+
     /// Returns the maximum value of the board
     pub fn max_value(self) -> u16 {
         let exponent = self.into_iter().max().unwrap();
         1 << exponent as u16
     }
 
+    /// Returns the sum of the values of the tiles
+    /// Contrary to the score of a game, which sums the values resulting from merges, this sum
+    /// only depends on the tiles which have been populated.
+    pub fn tile_sum(self) -> u32 {
+        self.into_iter()
+            .filter(|exponent| *exponent > 0)
+            .map(|exponent| 1 << exponent as u32)
+            .sum()
+    }
+
+    /// Returns the sums of the values of the tiles in each 2x2 quadrant, in the following order:
+    /// top-left, top-right, bottom-left and bottom-right
+    pub fn quadrant_sums(self) -> [u32; 4] {
+        self.tiles().fold([0; 4], |mut sums, (idx, value)| {
+            let quadrant = 2 * (idx / 8) + (idx % 4) / 2;
+            sums[quadrant as usize] += value as u32;
+            sums
+        })
+    }
+
+    /// Returns the `(index, value)` pairs of all the tiles, empty tiles having a value of `0`
+    pub fn tiles(self) -> impl Iterator<Item = (u8, u16)> {
+        self.into_iter()
+            .enumerate()
+            .map(|(idx, exponent)| match exponent {
+                0 => (idx as u8, 0),
+                exponent => (idx as u8, 1 << exponent as u16),
+            })
+    }
+
+    /// Returns the exponents of every pair of horizontally or vertically adjacent tiles
+    /// The 12 horizontal pairs come first, from left to right and top to bottom, followed by the
+    /// 12 vertical pairs, from top to bottom and left to right. Empty tiles are included.
+    pub fn adjacent_pairs(self) -> impl Iterator<Item = (u8, u8)> {
+        let lines = [self.state, self.transpose().state];
+        (0..24).map(move |pair_idx| {
+            let line = lines[pair_idx / 12] >> (16 * (3 - (pair_idx % 12) / 3));
+            let i = pair_idx % 3;
+            let left = (line >> (4 * (3 - i))) & 0xF;
+            let right = (line >> (4 * (2 - i))) & 0xF;
+            (left as u8, right as u8)
+        })
+    }
+
+    /// Returns the `(index, value)` pairs of the non-empty tiles
+    pub fn occupied_tiles(self) -> impl ExactSizeIterator<Item = (u8, u16)> {
+        OccupiedTilesIterator {
+            state: self.state,
+            index: 0,
+            remaining: 16 - count_zero_nibbles(self.state),
+        }
+    }
+
     /// Returns the indices of empty tiles
-    pub fn empty_tiles_indices(self) -> impl Iterator<Item = u8> {
+    pub fn empty_tiles_indices(self) -> impl ExactSizeIterator<Item = u8> {
         self.into_empty_tiles_iter()
     }
 
+    /// Returns the boards obtained by populating a 2 or a 4 on each empty tile, along with their
+    /// probability, a 4 being drawn with probability `proba_4`
+    /// The empty tile is chosen uniformly, so that the probabilities sum to 1 unless the board is
+    /// full, in which case no board is returned.
+    pub fn spawn_successors(self, proba_4: f32) -> Vec<(Board, f32)> {
+        let empty_tiles = self.empty_tiles_indices();
+        let nb_empty_tiles = empty_tiles.len() as f32;
+        empty_tiles
+            .flat_map(|idx| {
+                vec![
+                    (
+                        self.set_value_by_exponent(idx, 1),
+                        (1. - proba_4) / nb_empty_tiles,
+                    ),
+                    (self.set_value_by_exponent(idx, 2), proba_4 / nb_empty_tiles),
+                ]
+            })
+            .collect()
+    }
+
     /// Returns the number of empty tiles
     pub fn count_empty_tiles(self) -> usize {
-        self.empty_tiles_indices().fold(0, |mut acc, _| {
-            acc += 1;
-            acc
-        })
+        self.empty_tiles_indices().len()
+    }
+
+    /// Returns `true` when there is no empty tile
+    pub fn is_full(self) -> bool {
+        let x = self.state;
+        // the highest bit of a group is set in the result when the group is 0, or when a lower
+        // group is 0 due to the borrow, so the result is 0 if and only if no group is 0
+        x.wrapping_sub(0x1111_1111_1111_1111) & !x & 0x8888_8888_8888_8888 == 0
+    }
+
+    /// Returns the number of tiles having the provided value
+    /// This panics when the value is not a valid tile value.
+    pub fn count_value(self, value: u16) -> u8 {
+        let exponent = get_exponent(value);
+        // tiles having the provided value are set to 0
+        count_zero_nibbles(self.state ^ (exponent * 0x1111_1111_1111_1111))
     }
 
     /// Returns the number of distinct tiles, excluding empty tiles
@@ -133,6 +327,25 @@ impl Board {
         count
     }
 
+    /// Returns the minimum score with which the board can be reached
+    /// Each tile `2^k` with `k >= 2` results from merges which have scored at least `(k-1) * 2^k`,
+    /// assuming that only 2 tiles have been populated.
+    pub fn minimum_score(self) -> u32 {
+        self.into_iter()
+            .filter(|exponent| *exponent >= 2)
+            .map(|exponent| (exponent as u32 - 1) << exponent)
+            .sum()
+    }
+
+    /// Returns the tiles which differ between the two boards, as
+    /// `(tile_index, self_value, other_value)` triples
+    pub fn diff(self, other: Board) -> Vec<(u8, u16, u16)> {
+        (0..16)
+            .map(|idx| (idx, self.get_value(idx), other.get_value(idx)))
+            .filter(|(_, value, other_value)| value != other_value)
+            .collect()
+    }
+
     /// Moves the tiles in the provided `Direction` and returns the resulting `Board`
     pub fn move_to(self, direction: Direction) -> Self {
         match direction {
@@ -143,7 +356,146 @@ impl Board {
         }
     }
 
-    fn transpose(self) -> Self {
+    /// Moves the tiles in the provided `Direction` with the moves tables of a `TileScheme`, and
+    /// returns the resulting `Board`
+    pub fn move_with_tables(self, direction: Direction, tables: &MovesTables) -> Self {
+        match direction {
+            Direction::Left => self.into_left_with(&tables.left),
+            Direction::Right => self.into_left_with(&tables.right),
+            Direction::Up => self.into_up_with(&tables.left),
+            Direction::Down => self.into_up_with(&tables.right),
+        }
+    }
+
+    /// Moves the tiles in the provided `Direction`, in place, and returns whether the board changed
+    pub fn move_to_in_place(&mut self, direction: Direction) -> bool {
+        let new_board = self.move_to(direction);
+        let has_moved = new_board != *self;
+        *self = new_board;
+        has_moved
+    }
+
+    /// Returns the moves which change the board, along with the resulting boards, in the order of
+    /// `Direction::all()`
+    pub fn legal_move_boards(self) -> Vec<(Direction, Board)> {
+        Direction::all()
+            .iter()
+            .map(|d| (*d, self.move_to(*d)))
+            .filter(|(_, new_board)| *new_board != self)
+            .collect()
+    }
+
+    /// Moves the tiles in the provided `Direction` and then, when provided, places the spawned
+    /// tile given as an `(index, value)` pair
+    /// Contrary to `Game::step`, no randomness is involved and the spawn is placed even when the
+    /// move does not change the board.
+    pub fn apply(self, direction: Direction, spawn: Option<(u8, u16)>) -> Self {
+        let new_board = self.move_to(direction);
+        match spawn {
+            Some((tile_idx, tile_value)) => new_board.set_value(tile_idx, tile_value),
+            None => new_board,
+        }
+    }
+
+    /// Moves the tiles in the provided `Direction` and returns the resulting `Board` along with
+    /// the score gained, i.e. the sum of the values of the merged tiles
+    pub fn move_to_with_score(self, direction: Direction) -> (Self, u32) {
+        let lines = match direction {
+            Direction::Left | Direction::Right => self.rows(),
+            Direction::Up | Direction::Down => self.columns(),
+        };
+        let score = lines.iter().map(|line| SCORES_TABLE[*line as usize]).sum();
+        (self.move_to(direction), score)
+    }
+
+    /// Returns the number of pairs of tiles which would be merged by moving the tiles in the
+    /// provided `Direction`
+    pub fn merge_count(self, direction: Direction) -> u8 {
+        let lines = match direction {
+            Direction::Left | Direction::Right => self.rows(),
+            Direction::Up | Direction::Down => self.columns(),
+        };
+        lines
+            .iter()
+            .map(|line| MERGE_COUNTS_TABLE[*line as usize])
+            .sum()
+    }
+
+    /// Returns `true` when no move can change the board, i.e. when the game is over
+    pub fn is_game_over(self) -> bool {
+        Direction::all()
+            .iter()
+            .all(|direction| self.move_to(*direction) == self)
+    }
+
+    /// Returns the canonical form of the board, which is the same for the 8 boards which can be
+    /// obtained from one another through rotations and reflections
+    /// The canonical form is the lexicographically smallest of these 8 symmetric boards.
+    pub fn canonical(self) -> Self {
+        let transposed = self.transpose();
+        [
+            self,
+            self.reverse_rows(),
+            self.reverse_columns(),
+            self.reverse_rows().reverse_columns(),
+            transposed,
+            transposed.reverse_rows(),
+            transposed.reverse_columns(),
+            transposed.reverse_rows().reverse_columns(),
+        ]
+        .iter()
+        .min_by_key(|board| board.state)
+        .copied()
+        .unwrap()
+    }
+
+    /// Returns `true` when the boards are the same up to rotations and reflections
+    pub fn equivalent_to(self, other: Board) -> bool {
+        self.canonical() == other.canonical()
+    }
+
+    /// Rotates the board by 90 degrees clockwise
+    /// Applying this rotation four times gives back the initial board.
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().reverse_rows()
+    }
+
+    /// Rotates the board by 90 degrees counterclockwise
+    pub fn rotate_ccw(self) -> Self {
+        self.reverse_rows().transpose()
+    }
+
+    /// Mirrors the board horizontally, i.e. swaps its left and right sides
+    pub fn mirror_horizontal(self) -> Self {
+        self.reverse_rows()
+    }
+
+    /// Mirrors the board vertically, i.e. swaps its top and bottom sides
+    pub fn mirror_vertical(self) -> Self {
+        self.reverse_columns()
+    }
+
+    /// Reverses the order of the tiles within each row
+    fn reverse_rows(self) -> Self {
+        let x = self.state;
+        let x = ((x & 0xF0F0_F0F0_F0F0_F0F0) >> 4) | ((x & 0x0F0F_0F0F_0F0F_0F0F) << 4);
+        let x = ((x & 0xFF00_FF00_FF00_FF00) >> 8) | ((x & 0x00FF_00FF_00FF_00FF) << 8);
+        Self { state: x }
+    }
+
+    /// Reverses the order of the tiles within each column
+    fn reverse_columns(self) -> Self {
+        let x = self.state;
+        let x = ((x & 0xFFFF_0000_FFFF_0000) >> 16) | ((x & 0x0000_FFFF_0000_FFFF) << 16);
+        Self {
+            state: x.rotate_left(32),
+        }
+    }
+
+    /// Transposes the board, i.e. swaps its rows and columns
+    /// The transposition is performed in a few masks and shifts, by swapping the 2x2 blocks of
+    /// tiles located on each side of the diagonal and then the tiles within these blocks.
+    pub fn transpose(self) -> Self {
         // Credit to nneonneo for this fast tranpose implementation
         // https://github.com/nneonneo/2048-ai/blob/master/2048.cpp
         let x = self.state;
@@ -159,47 +511,40 @@ impl Board {
     }
 
     fn into_left(self) -> Self {
-        self.rows()
-            .iter()
-            .enumerate()
-            .fold(Board::default(), |mut acc, (row_idx, row)| {
-                acc.state |=
-                    (LEFT_MOVES_TABLE[*row as usize] as u64) << (16 * (3 - row_idx) as u64);
-                acc
-            })
+        self.into_left_with(moves_tables().0)
     }
 
     fn into_right(self) -> Self {
+        self.into_left_with(moves_tables().1)
+    }
+
+    fn into_up(self) -> Self {
+        self.into_up_with(moves_tables().0)
+    }
+
+    fn into_down(self) -> Self {
+        self.into_up_with(moves_tables().1)
+    }
+
+    /// Moves each row with the provided moves table, which is the left or the right one
+    /// The table is generic so that indexing the static tables does not need bounds checks.
+    fn into_left_with<T: Index<usize, Output = u16>>(self, moves_table: &T) -> Self {
         self.rows()
             .iter()
             .enumerate()
             .fold(Board::default(), |mut acc, (row_idx, row)| {
-                acc.state |=
-                    (RIGHT_MOVES_TABLE[*row as usize] as u64) << (16 * (3 - row_idx) as u64);
+                acc.state |= (moves_table[*row as usize] as u64) << (16 * (3 - row_idx) as u64);
                 acc
             })
     }
 
-    fn into_up(self) -> Self {
-        self.transpose().rows().iter().enumerate().fold(
-            Board::default(),
-            |mut acc, (col_idx, col)| {
-                let up_col = LEFT_MOVES_TABLE[*col as usize] as u64;
-                let col_shift = 4 * (3 - col_idx) as u64;
-                acc.state |= (up_col & 0xF000) << (36 + col_shift);
-                acc.state |= (up_col & 0xF00) << (24 + col_shift);
-                acc.state |= (up_col & 0xF0) << (12 + col_shift);
-                acc.state |= (up_col & 0xF) << col_shift;
-                acc
-            },
-        )
-    }
-
-    fn into_down(self) -> Self {
+    /// Moves each column with the provided moves table, the left one moving the tiles up and the
+    /// right one moving them down
+    fn into_up_with<T: Index<usize, Output = u16>>(self, moves_table: &T) -> Self {
         self.transpose().rows().iter().enumerate().fold(
             Board::default(),
             |mut acc, (col_idx, col)| {
-                let up_col = RIGHT_MOVES_TABLE[*col as usize] as u64;
+                let up_col = moves_table[*col as usize] as u64;
                 let col_shift = 4 * (3 - col_idx) as u64;
                 acc.state |= (up_col & 0xF000) << (36 + col_shift);
                 acc.state |= (up_col & 0xF00) << (24 + col_shift);
@@ -249,13 +594,25 @@ impl Board {
         EmptyTilesIterator {
             state: self.state,
             index: 0,
+            remaining: count_zero_nibbles(self.state),
         }
     }
 }
 
+/// Returns the number of 4 bits groups which are equal to 0
+fn count_zero_nibbles(x: u64) -> u8 {
+    // collapse each group into its lowest bit, which is set if and only if the group is not 0
+    let mut non_zero = x | (x >> 1);
+    non_zero |= non_zero >> 2;
+    non_zero &= 0x1111_1111_1111_1111;
+    16 - non_zero.count_ones() as u8
+}
+
 pub struct EmptyTilesIterator {
     state: u64,
     index: u8,
+    /// number of empty tiles which have not been yielded yet
+    remaining: u8,
 }
 
 impl Iterator for EmptyTilesIterator {
@@ -274,16 +631,64 @@ impl Iterator for EmptyTilesIterator {
                     self.state <<= 4;
                     self.index += 1;
                     if empty_tile_index.is_some() {
+                        self.remaining -= 1;
                         return empty_tile_index;
                     }
                 }
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
+}
+
+impl ExactSizeIterator for EmptyTilesIterator {}
+
+struct OccupiedTilesIterator {
+    state: u64,
+    index: u8,
+    /// number of non-empty tiles which have not been yielded yet
+    remaining: u8,
+}
+
+impl Iterator for OccupiedTilesIterator {
+    type Item = (u8, u16);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let leading_zeros = self.state.leading_zeros();
+            if leading_zeros >= 4 {
+                // skip all the empty tiles preceding the next non-empty tile at once
+                let skipped_tiles = leading_zeros / 4;
+                self.state <<= 4 * skipped_tiles;
+                self.index += skipped_tiles as u8;
+                continue;
+            }
+            let tile = (self.index, 1 << (self.state >> 60) as u16);
+            self.state <<= 4;
+            self.index += 1;
+            self.remaining -= 1;
+            return Some(tile);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining as usize, Some(self.remaining as usize))
+    }
 }
 
+impl ExactSizeIterator for OccupiedTilesIterator {}
+
+/// Converts 16 tile values into a `Board`
+///
+/// This conversion panics when the number of tiles or a tile value is invalid,
+/// `Board::try_from_vec` must be used when the tiles are not known to be valid.
 impl From<Vec<u16>> for Board {
     fn from(tiles: Vec<u16>) -> Self {
+        assert_eq!(16, tiles.len(), "Expected 16 tiles");
         let mut state: u64 = 0;
         for tile_value in tiles.into_iter() {
             state <<= 4;
@@ -293,6 +698,88 @@ impl From<Vec<u16>> for Board {
     }
 }
 
+impl TryFrom<&[u16]> for Board {
+    type Error = Error;
+
+    fn try_from(tiles: &[u16]) -> Result<Self, Self::Error> {
+        if tiles.len() != 16 {
+            return Err(Error::new(
+                ErrorKind::InvalidBoardRepr,
+                format!("Expected 16 tiles but found {}", tiles.len()),
+            ));
+        }
+        let mut state: u64 = 0;
+        for &tile_value in tiles.iter() {
+            let exponent = try_get_exponent(tile_value).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidSquareValue(tile_value),
+                    format!("Invalid tile value {}", tile_value),
+                )
+            })?;
+            state <<= 4;
+            state |= exponent;
+        }
+        Ok(Self { state })
+    }
+}
+
+/// Parses a `Board` from 16 tile values separated by whitespaces, `0` being used for empty tiles
+/// Tiles are listed row by row, so line breaks can be used to separate rows.
+impl FromStr for Board {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = s
+            .split_whitespace()
+            .map(|token| {
+                u16::from_str(token).map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidBoardRepr,
+                        format!("Invalid tile token '{}'", token),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::try_from(tiles.as_slice())
+    }
+}
+
+/// `Board` is serialized as the flat sequence of its 16 tile values, rather than its internal
+/// `u64` representation, in order to keep the serialized format human readable
+#[cfg(feature = "serde")]
+impl serde::Serialize for Board {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Vec::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Board {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let tiles = Vec::<u16>::deserialize(deserializer)?;
+        Board::try_from(tiles.as_slice()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Builds a `Board` from `(index, value)` pairs, the tiles which are not listed being empty
+///
+/// Like `Board::set_value`, this panics when a value is not a valid tile value.
+impl FromIterator<(u8, u16)> for Board {
+    fn from_iter<I: IntoIterator<Item = (u8, u16)>>(tiles: I) -> Self {
+        tiles
+            .into_iter()
+            .fold(Board::default(), |board, (tile_idx, tile_value)| {
+                board.set_value(tile_idx, tile_value)
+            })
+    }
+}
+
 impl From<Board> for Vec<u16> {
     fn from(board: Board) -> Self {
         board
@@ -309,63 +796,194 @@ impl From<Board> for Vec<u16> {
 }
 
 impl Board {
-    fn display(self, f: &mut Formatter<'_>, debug: bool) -> Result<(), std::fmt::Error> {
+    /// Renders the board as a grid, according to the provided `RenderOptions`
+    pub fn render(self, options: RenderOptions) -> String {
+        self.render_with_line_break(options, "\n")
+    }
+
+    /// Renders the board like `render`, with the tile values of a `TileScheme`
+    pub fn render_in_scheme(self, scheme: &dyn TileScheme, options: RenderOptions) -> String {
+        Self::render_values(self.values_in_scheme(scheme), options, "\n")
+    }
+
+    /// Builds a board from its 16 tile values in a `TileScheme`
+    /// An error is returned when there are not 16 values or when a value is not a valid tile
+    /// value of the scheme.
+    pub fn from_values_in_scheme(values: &[u16], scheme: &dyn TileScheme) -> Result<Self, Error> {
+        if values.len() != 16 {
+            return Err(Error::new(
+                ErrorKind::InvalidBoardRepr,
+                format!("Expected 16 tiles but found {}", values.len()),
+            ));
+        }
+        let mut state: u64 = 0;
+        for &value in values.iter() {
+            // slots returned by a user scheme must fit in the 4 bits of a tile
+            let slot = scheme
+                .try_value_to_slot(value)
+                .filter(|slot| *slot < 16)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidSquareValue(value),
+                        format!("Invalid tile value {}", value),
+                    )
+                })?;
+            state <<= 4;
+            state |= slot;
+        }
+        Ok(Self { state })
+    }
+
+    /// Returns the 16 tile values in a `TileScheme`
+    pub fn values_in_scheme(self, scheme: &dyn TileScheme) -> Vec<u16> {
+        self.into_iter()
+            .map(|slot| scheme.slot_to_value(slot as u64))
+            .collect()
+    }
+
+    fn render_with_line_break(self, options: RenderOptions, line_break: &str) -> String {
+        Self::render_values(Vec::from(self), options, line_break)
+    }
+
+    /// Renders the 16 tile values of a board, see `render`
+    fn render_values(values: Vec<u16>, options: RenderOptions, line_break: &str) -> String {
+        let (vertical, horizontal) = if options.ascii_only {
+            ("|", "-------")
+        } else {
+            ("║", "═══════")
+        };
+        let border = |[left, middle, right]: [&str; 3]| {
+            format!(
+                "{left}{h}{middle}{h}{middle}{h}{middle}{h}{right}{b}",
+                left = left,
+                middle = middle,
+                right = right,
+                h = horizontal,
+                b = line_break
+            )
+        };
+        let (top, separator, bottom) = if options.ascii_only {
+            (["+"; 3], ["+"; 3], ["+"; 3])
+        } else {
+            (["╔", "╦", "╗"], ["╠", "╬", "╣"], ["╚", "╩", "╝"])
+        };
         let mut display = String::new();
-        let line_break = if debug { "\n" } else { "\n\r" };
-        display.push_str(&*format!(
-            "{b}╔═══════╦═══════╦═══════╦═══════╗{b}",
-            b = line_break
-        ));
-        for (i, tile) in Vec::from(self).into_iter().enumerate() {
+        display.push_str(line_break);
+        display.push_str(&border(top));
+        for (i, tile) in values.into_iter().enumerate() {
             if tile == 0 {
-                display.push_str("║       ");
-            } else if debug {
-                display.push_str(&*format!(
-                    "║{prefix}{tile} ",
+                display.push_str(&format!("{}       ", vertical));
+            } else if options.color {
+                display.push_str(&format!(
+                    "{vertical}{prefix}{tile} ",
+                    vertical = vertical,
                     prefix = get_spaces_prefix(tile),
-                    tile = tile,
+                    tile = colorize_tile(tile),
                 ));
             } else {
-                display.push_str(&*format!(
-                    "║{prefix}{color}{tile}{reset} ",
+                display.push_str(&format!(
+                    "{vertical}{prefix}{tile} ",
+                    vertical = vertical,
                     prefix = get_spaces_prefix(tile),
-                    color = get_color(tile),
                     tile = tile,
-                    reset = color::Fg(color::Reset)
                 ));
             }
             if i % 4 == 3 {
-                display.push_str(&*format!("║{b}", b = line_break));
+                display.push_str(&format!("{}{}", vertical, line_break));
                 if i == 15 {
-                    display.push_str(&*format!(
-                        "╚═══════╩═══════╩═══════╩═══════╝{b}",
-                        b = line_break
-                    ));
+                    display.push_str(&border(bottom));
                 } else {
-                    display.push_str(&*format!(
-                        "╠═══════╬═══════╬═══════╬═══════╣{b}",
-                        b = line_break
-                    ));
+                    display.push_str(&border(separator));
                 }
             }
         }
-        write!(f, "{}", display)
+        display
+    }
+}
+
+impl Board {
+    /// Returns a single-line representation of the board, suited for logs, in which rows are
+    /// separated by `/` and empty tiles are represented by `.`
+    /// This representation can be parsed back with `Board::from_compact_str`.
+    pub fn to_compact_string(self) -> String {
+        self.rows()
+            .iter()
+            .map(|row| {
+                (0..4)
+                    .map(|i| match (row >> (4 * (3 - i))) & 0xF {
+                        0 => ".".to_string(),
+                        exponent => (1 << exponent as u32).to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// Parses a board from its representation obtained with `Board::to_compact_string`
+    pub fn from_compact_str(s: &str) -> Result<Self, Error> {
+        let rows: Vec<_> = s.split('/').collect();
+        if rows.len() != 4 {
+            return Err(Error::new(
+                ErrorKind::InvalidBoardRepr,
+                format!("Expected 4 rows but found {}", rows.len()),
+            ));
+        }
+        let mut tiles = Vec::with_capacity(16);
+        for row in rows {
+            let row_tiles: Vec<_> = row.split_whitespace().collect();
+            if row_tiles.len() != 4 {
+                return Err(Error::new(
+                    ErrorKind::InvalidBoardRepr,
+                    format!("Expected 4 tiles in row '{}'", row.trim()),
+                ));
+            }
+            for token in row_tiles {
+                let tile = match token {
+                    "." => 0,
+                    _ => u16::from_str(token).map_err(|_| {
+                        Error::new(
+                            ErrorKind::InvalidBoardRepr,
+                            format!("Invalid tile token '{}'", token),
+                        )
+                    })?,
+                };
+                tiles.push(tile);
+            }
+        }
+        Self::try_from(tiles.as_slice())
     }
 }
 
+/// Options controlling how a `Board` is rendered
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RenderOptions {
+    /// whether or not the tiles are colorized with ANSI escape codes
+    pub color: bool,
+    /// whether or not the grid is drawn with ASCII characters instead of box-drawing ones
+    pub ascii_only: bool,
+}
+
+/// Displays the colorized board in a terminal in raw mode
+/// Without the `cli` feature, the board is displayed with plain ASCII characters.
 impl Display for Board {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        self.display(f, false)
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let options = RenderOptions {
+            color: cfg!(feature = "cli"),
+            ascii_only: !cfg!(feature = "cli"),
+        };
+        write!(f, "{}", self.render_with_line_break(options, "\n\r"))
     }
 }
 
 impl Debug for Board {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        self.display(f, true)
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.render(RenderOptions::default()))
     }
 }
 
-fn get_spaces_prefix(tile: u16) -> &'static str {
+pub(crate) fn get_spaces_prefix(tile: u16) -> &'static str {
     if tile < 10 {
         "     "
     } else if tile < 100 {
@@ -379,30 +997,108 @@ fn get_spaces_prefix(tile: u16) -> &'static str {
     }
 }
 
-fn get_color(tile: u16) -> color::Fg<color::Rgb> {
-    match tile {
-        2 => color::Fg(color::Rgb(238, 228, 218)),
-        4 => color::Fg(color::Rgb(237, 224, 200)),
-        8 => color::Fg(color::Rgb(242, 177, 121)),
-        16 => color::Fg(color::Rgb(245, 149, 99)),
-        32 => color::Fg(color::Rgb(246, 124, 95)),
-        64 => color::Fg(color::Rgb(246, 94, 59)),
-        128 => color::Fg(color::Rgb(237, 207, 114)),
-        256 => color::Fg(color::Rgb(237, 204, 97)),
-        512 => color::Fg(color::Rgb(237, 200, 80)),
-        1024 => color::Fg(color::Rgb(237, 197, 63)),
-        2048 => color::Fg(color::Rgb(237, 194, 46)),
-        4096 => color::Fg(color::Rgb(129, 214, 154)),
-        8192 => color::Fg(color::Rgb(129, 214, 154)),
-        16384 => color::Fg(color::Rgb(129, 214, 154)),
-        32768 => color::Fg(color::Rgb(129, 214, 154)),
-        _ => panic!("Invalid tile value: {}", tile),
+/// Returns the tile value wrapped in the ANSI escape codes of its color
+#[cfg(feature = "cli")]
+pub(crate) fn colorize_tile(tile: u16) -> String {
+    format!(
+        "{}{}{}",
+        get_color(tile.trailing_zeros() as u8),
+        tile,
+        color::Fg(color::Reset)
+    )
+}
+
+/// Returns the tile value as is, colors being only supported with the `cli` feature
+#[cfg(not(feature = "cli"))]
+pub(crate) fn colorize_tile(tile: u16) -> String {
+    tile.to_string()
+}
+
+#[cfg(feature = "cli")]
+fn get_color(exponent: u8) -> color::Fg<color::Rgb> {
+    match exponent {
+        1 => color::Fg(color::Rgb(238, 228, 218)),
+        2 => color::Fg(color::Rgb(237, 224, 200)),
+        3 => color::Fg(color::Rgb(242, 177, 121)),
+        4 => color::Fg(color::Rgb(245, 149, 99)),
+        5 => color::Fg(color::Rgb(246, 124, 95)),
+        6 => color::Fg(color::Rgb(246, 94, 59)),
+        7 => color::Fg(color::Rgb(237, 207, 114)),
+        8 => color::Fg(color::Rgb(237, 204, 97)),
+        9 => color::Fg(color::Rgb(237, 200, 80)),
+        10 => color::Fg(color::Rgb(237, 197, 63)),
+        11 => color::Fg(color::Rgb(237, 194, 46)),
+        _ => color::Fg(get_extrapolated_color(exponent)),
     }
 }
 
+/// Returns a color for the tiles above 2048, starting from green for 4096 and shifting the hue
+/// by 30 degrees for each following exponent
+#[cfg(feature = "cli")]
+fn get_extrapolated_color(exponent: u8) -> color::Rgb {
+    let hue = (140 + 30 * exponent as u32) % 360;
+    // value and saturation of the green color of 4096
+    let value = 214.;
+    let chroma = value * 0.4;
+    let sector = hue as f32 / 60.;
+    let x = chroma * (1. - (sector % 2. - 1.).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.),
+        1 => (x, chroma, 0.),
+        2 => (0., chroma, x),
+        3 => (0., x, chroma),
+        4 => (x, 0., chroma),
+        _ => (chroma, 0., x),
+    };
+    let m = value - chroma;
+    color::Rgb((r + m) as u8, (g + m) as u8, (b + m) as u8)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::GameBuilder;
+
+    #[test]
+    fn should_parse_direction() {
+        // Given
+        let names = ["left", "R", "Up", "d"];
+
+        // When
+        let directions: Vec<_> = names.iter().map(|name| Direction::from_str(name)).collect();
+        let invalid_direction = Direction::from_str("diagonal");
+
+        // Then
+        let expected_directions = vec![
+            Ok(Direction::Left),
+            Ok(Direction::Right),
+            Ok(Direction::Up),
+            Ok(Direction::Down),
+        ];
+        assert_eq!(expected_directions, directions);
+        assert_eq!(
+            Some(ErrorKind::InvalidDirection),
+            invalid_direction.err().map(|e| e.kind().clone())
+        );
+    }
+
+    #[test]
+    fn should_get_opposite_direction() {
+        // When
+        let opposites: Vec<_> = Direction::all().iter().map(|d| d.opposite()).collect();
+
+        // Then
+        let expected_opposites = vec![
+            Direction::Right,
+            Direction::Left,
+            Direction::Down,
+            Direction::Up,
+        ];
+        assert_eq!(expected_opposites, opposites);
+        for direction in Direction::all().iter() {
+            assert_eq!(*direction, direction.opposite().opposite());
+        }
+    }
 
     #[test]
     fn should_convert_vec_to_board() {
@@ -424,39 +1120,293 @@ mod tests {
     }
 
     #[test]
-    fn should_iterate_over_exponents() {
+    fn should_convert_board_to_and_from_raw() {
         // Given
         #[rustfmt::skip]
-        let vec_board: Vec<u16> = vec![
+        let board = Board::from(vec![
             0, 2, 0, 0,
             32768, 0, 0, 2,
             0, 0, 16, 4,
             8, 2, 16, 64
-        ];
-        let board = Board::from(vec_board.clone());
+        ]);
 
         // When
-        let exponents: Vec<_> = board.into_iter().collect();
+        let raw = board.as_raw();
+        let raw_board = Board::from_raw(0x0100_F001_0042_3146);
 
         // Then
+        assert_eq!(0x0100_F001_0042_3146, raw);
+        assert_eq!(board, raw_board);
+        assert_eq!(board, Board::from_raw(board.as_raw()));
+    }
+
+    #[test]
+    fn should_build_board_from_exponents() {
+        // Given
         #[rustfmt::skip]
-        let expected_exponents = vec![
+        let exponents = [
             0, 1, 0, 0,
             15, 0, 0, 1,
             0, 0, 4, 2,
             3, 1, 4, 6
         ];
-        assert_eq!(expected_exponents, exponents);
-    }
 
-    #[test]
-    fn should_use_binary_representation() {
-        // Given
+        // When
+        let board = Board::from_exponents(exponents);
+
+        // Then
         #[rustfmt::skip]
-        let board_values = vec![
-            0, 0, 0, 0,
-            0, 0, 0, 0,
-            0, 0, 4, 0,
+        let expected_board = Board::from(vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 16, 4,
+            8, 2, 16, 64
+        ]);
+        assert_eq!(expected_board, board);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid exponent 16")]
+    fn should_panic_when_building_board_from_invalid_exponent() {
+        // Given
+        let mut exponents = [0; 16];
+        exponents[5] = 16;
+
+        // When
+        Board::from_exponents(exponents);
+    }
+
+    #[test]
+    fn should_try_convert_vec_to_board() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board: Vec<u16> = vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 16, 4,
+            8, 2, 16, 64
+        ];
+
+        // When
+        let board = Board::try_from(vec_board.as_slice());
+
+        // Then
+        assert_eq!(Ok(Board::from(vec_board)), board);
+    }
+
+    #[test]
+    fn should_fail_to_convert_vec_with_bad_length() {
+        // Given
+        let vec_board: Vec<u16> = vec![0, 2, 0, 0, 2];
+
+        // When
+        let result = Board::try_from(vec_board.as_slice());
+
+        // Then
+        assert_eq!(&ErrorKind::InvalidBoardRepr, result.unwrap_err().kind());
+    }
+
+    #[test]
+    fn should_fail_to_convert_owned_vec_with_invalid_value() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board: Vec<u16> = vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 3, 4,
+            8, 2, 16, 64
+        ];
+
+        // When
+        let result = Board::try_from_vec(vec_board);
+
+        // Then
+        assert_eq!(
+            &ErrorKind::InvalidSquareValue(3),
+            result.unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_convert_vec_with_invalid_value() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board: Vec<u16> = vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 3, 4,
+            8, 2, 16, 64
+        ];
+
+        // When
+        let result = Board::try_from(vec_board.as_slice());
+
+        // Then
+        assert_eq!(
+            &ErrorKind::InvalidSquareValue(3),
+            result.unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_convert_vec_with_out_of_range_value() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board: Vec<u16> = vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 2, 4,
+            8, 2, 16, 65535
+        ];
+
+        // When
+        let result = Board::try_from(vec_board.as_slice());
+
+        // Then
+        assert_eq!(
+            &ErrorKind::InvalidSquareValue(65535),
+            result.unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 16 tiles")]
+    fn should_panic_when_converting_vec_with_bad_length() {
+        let _ = Board::from(vec![0, 2, 0, 0, 2]);
+    }
+
+    #[test]
+    fn should_parse_board_from_display() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            8192, 32, 16384, 32768,
+            4096, 256, 0, 512,
+            2048, 128, 1024, 4,
+            8, 2, 16, 64
+        ]);
+        let display: String = format!("{:?}", board)
+            .replace("║       ", " 0 ")
+            .chars()
+            .map(|c| if c.is_ascii_digit() { c } else { ' ' })
+            .collect();
+
+        // When
+        let parsed_board = display.parse::<Board>();
+
+        // Then
+        assert_eq!(Ok(board), parsed_board);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_board() {
+        // Given
+        let board_str = "0 2 0 0 \n 0 2 0 0 \n 0 2 0 0 \n 0 2 0 6";
+
+        // When
+        let result = board_str.parse::<Board>();
+
+        // Then
+        assert_eq!(
+            &ErrorKind::InvalidSquareValue(6),
+            result.unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn should_convert_board_to_compact_string_and_back() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 0, 4, 8,
+            0, 0, 16, 16,
+            32768, 0, 0, 0,
+            0, 0, 0, 2,
+        ]);
+
+        // When
+        let compact_string = board.to_compact_string();
+        let parsed_board = Board::from_compact_str(&compact_string);
+
+        // Then
+        assert_eq!(
+            "2 . 4 8 / . . 16 16 / 32768 . . . / . . . 2",
+            compact_string
+        );
+        assert_eq!(Ok(board), parsed_board.map_err(|e| e.kind().clone()));
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_compact_string() {
+        // Given
+        let compact_string = "2 . 4 8 / . . 16 16 / . . . .";
+
+        // When
+        let result = Board::from_compact_str(compact_string);
+
+        // Then
+        assert_eq!(
+            Some(ErrorKind::InvalidBoardRepr),
+            result.err().map(|e| e.kind().clone())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_serialize_and_deserialize_board() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 16, 4,
+            8, 2, 16, 64
+        ]);
+
+        // When
+        let json = serde_json::to_string(&board).unwrap();
+        let deserialized_board: Board = serde_json::from_str(&json).unwrap();
+
+        // Then
+        assert_eq!("[0,2,0,0,32768,0,0,2,0,0,16,4,8,2,16,64]", json);
+        assert_eq!(board, deserialized_board);
+    }
+
+    #[test]
+    fn should_iterate_over_exponents() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board: Vec<u16> = vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 16, 4,
+            8, 2, 16, 64
+        ];
+        let board = Board::from(vec_board.clone());
+
+        // When
+        let exponents: Vec<_> = board.into_iter().collect();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_exponents = vec![
+            0, 1, 0, 0,
+            15, 0, 0, 1,
+            0, 0, 4, 2,
+            3, 1, 4, 6
+        ];
+        assert_eq!(expected_exponents, exponents);
+    }
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn should_use_binary_representation() {
+        // Given
+        #[rustfmt::skip]
+        let board_values = vec![
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 4, 0,
             0, 16, 0, 8
         ];
 
@@ -466,7 +1416,7 @@ mod tests {
         // Then
         let board_repr: u64 =
             2u64.pow(0 + 0) + 2u64.pow(1 + 0) + 2u64.pow(2 + 8) + 2u64.pow(1 + 20);
-        assert_eq!(board_repr as u64, board.state);
+        assert_eq!(board_repr, board.state);
     }
 
     #[test]
@@ -590,147 +1540,709 @@ mod tests {
     }
 
     #[test]
-    fn should_move_left() {
+    fn should_transpose() {
         // Given
         #[rustfmt::skip]
         let board = Board::from(vec![
-            0, 0, 0, 2,
-            2, 2, 4, 0,
-            4, 2, 8, 512,
-            16, 16, 32, 32,
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
         ]);
 
         // When
-        let left_board = board.into_left();
+        let transposed_board = board.transpose();
 
         // Then
         #[rustfmt::skip]
         let expected_board = Board::from(vec![
-            2, 0, 0, 0,
-            4, 4, 0, 0,
-            4, 2, 8, 512,
-            32, 64, 0, 0,
+            0, 4, 0, 2,
+            2, 0, 4, 0,
+            4, 2, 0, 512,
+            16, 8, 32, 32,
         ]);
-        assert_eq!(expected_board, left_board);
+        assert_eq!(expected_board, transposed_board);
+        assert_eq!(board, transposed_board.transpose());
+        assert_eq!(board.columns(), transposed_board.rows());
     }
 
     #[test]
-    fn should_move_right() {
+    fn should_reverse_rows() {
         // Given
         #[rustfmt::skip]
         let board = Board::from(vec![
-            512, 8, 2, 4,
-            2, 0, 0, 0,
-            0, 4, 2, 2,
-            32, 32, 16, 16,
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
         ]);
 
         // When
-        let right_board = board.into_right();
+        let reversed_board = board.reverse_rows();
 
         // Then
         #[rustfmt::skip]
         let expected_board = Board::from(vec![
-            512, 8, 2, 4,
-            0, 0, 0, 2,
-            0, 0, 4, 4,
-            0, 0, 64, 32,
+            16, 4, 2, 0,
+            8, 2, 0, 4,
+            32, 0, 4, 0,
+            32, 512, 0, 2,
         ]);
-        assert_eq!(expected_board, right_board);
+        assert_eq!(expected_board, reversed_board);
     }
 
     #[test]
-    fn should_move_up() {
+    fn should_reverse_columns() {
         // Given
         #[rustfmt::skip]
         let board = Board::from(vec![
-            0, 2, 512, 16,
-            0, 2, 8, 16,
-            0, 4, 2, 32,
-            2, 0, 4, 32,
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
         ]);
 
         // When
-        let up_board = board.into_up();
+        let reversed_board = board.reverse_columns();
 
         // Then
         #[rustfmt::skip]
         let expected_board = Board::from(vec![
-            2, 4, 512, 32,
-            0, 4, 8, 64,
-            0, 0, 2, 0,
-            0, 0, 4, 0,
+            2, 0, 512, 32,
+            0, 4, 0, 32,
+            4, 0, 2, 8,
+            0, 2, 4, 16,
         ]);
-        assert_eq!(expected_board, up_board);
+        assert_eq!(expected_board, reversed_board);
     }
 
     #[test]
-    fn should_move_down() {
+    fn should_rotate_clockwise() {
         // Given
         #[rustfmt::skip]
         let board = Board::from(vec![
-            2, 0, 512, 32,
-            0, 4, 8, 32,
-            0, 2, 2, 16,
             0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
         ]);
 
         // When
-        let down_board = board.into_down();
+        let rotated_board = board.rotate_cw();
 
         // Then
         #[rustfmt::skip]
         let expected_board = Board::from(vec![
-            0, 0, 512, 0,
-            0, 0, 8, 0,
-            0, 4, 2, 64,
-            2, 4, 4, 32,
+            2, 0, 4, 0,
+            0, 4, 0, 2,
+            512, 0, 2, 4,
+            32, 32, 8, 16,
         ]);
-        assert_eq!(expected_board, down_board);
+        assert_eq!(expected_board, rotated_board);
     }
 
     #[test]
-    fn should_move_with_high_values() {
+    fn should_rotate_counterclockwise() {
         // Given
         #[rustfmt::skip]
         let board = Board::from(vec![
-            0, 0, 0, 0,
-            0, 0, 16384, 0,
-            0, 0, 16384, 0,
-            0, 0, 0, 0,
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
         ]);
 
         // When
-        let down_board = board.into_down();
+        let rotated_board = board.rotate_ccw();
 
         // Then
         #[rustfmt::skip]
         let expected_board = Board::from(vec![
-            0, 0, 0, 0,
-            0, 0, 0, 0,
-            0, 0, 0, 0,
-            0, 0, 32768, 0,
+            16, 8, 32, 32,
+            4, 2, 0, 512,
+            2, 0, 4, 0,
+            0, 4, 0, 2,
+        ]);
+        assert_eq!(expected_board, rotated_board);
+    }
+
+    #[test]
+    fn should_mirror_horizontally() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
+        ]);
+
+        // When
+        let mirrored_board = board.mirror_horizontal();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            16, 4, 2, 0,
+            8, 2, 0, 4,
+            32, 0, 4, 0,
+            32, 512, 0, 2,
+        ]);
+        assert_eq!(expected_board, mirrored_board);
+    }
+
+    #[test]
+    fn should_mirror_vertically() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
+        ]);
+
+        // When
+        let mirrored_board = board.mirror_vertical();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            2, 0, 512, 32,
+            0, 4, 0, 32,
+            4, 0, 2, 8,
+            0, 2, 4, 16,
+        ]);
+        assert_eq!(expected_board, mirrored_board);
+    }
+
+    #[test]
+    fn should_be_identity_to_rotate_four_times() {
+        // Given
+        let states = (0..100u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+        for state in states {
+            let board = Board { state };
+
+            // When
+            let rotated_board = board.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+
+            // Then
+            assert_eq!(board, rotated_board);
+            assert_eq!(board, board.rotate_cw().rotate_ccw());
+        }
+    }
+
+    #[test]
+    fn should_share_canonical_form_with_rotated_board() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 4, 16,
+            4, 0, 2, 8,
+            0, 4, 0, 32,
+            2, 0, 512, 32,
+        ]);
+        #[rustfmt::skip]
+        let rotated_board = Board::from(vec![
+            2, 0, 4, 0,
+            0, 4, 0, 2,
+            512, 0, 2, 4,
+            32, 32, 8, 16,
+        ]);
+
+        // When
+        let canonical = board.canonical();
+        let rotated_canonical = rotated_board.canonical();
+
+        // Then
+        assert_eq!(canonical, rotated_canonical);
+        assert_eq!(canonical, canonical.canonical());
+    }
+
+    #[test]
+    fn should_be_equivalent_to_mirrored_board() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 0, 2048,
+            0, 256, 0, 512,
+            0, 0, 1024, 4,
+            8, 2, 16, 64
+        ]);
+        let mirrored_board = board.mirror_horizontal();
+
+        // When
+        let is_equivalent = board.equivalent_to(mirrored_board);
+        let is_equivalent_to_other = board.equivalent_to(board.set_value(0, 2));
+
+        // Then
+        assert!(is_equivalent);
+        assert_ne!(board, mirrored_board);
+        assert!(!is_equivalent_to_other);
+    }
+
+    #[test]
+    fn should_move_in_place_like_by_value() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            8, 8, 0, 8,
+            8, 0, 8, 8,
+            0, 8, 8, 0,
+            8, 0, 0, 0,
+        ]);
+
+        for direction in Direction::all() {
+            // When
+            let mut moved_board = board;
+            let has_moved = moved_board.move_to_in_place(*direction);
+
+            // Then
+            assert_eq!(board.move_to(*direction), moved_board);
+            assert_eq!(board != moved_board, has_moved);
+        }
+        let mut moved_board = board.move_to(Direction::Up);
+        assert!(!moved_board.move_to_in_place(Direction::Up));
+    }
+
+    #[test]
+    fn should_move_left() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 0, 0, 2,
+            2, 2, 4, 0,
+            4, 2, 8, 512,
+            16, 16, 32, 32,
+        ]);
+
+        // When
+        let left_board = board.into_left();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            2, 0, 0, 0,
+            4, 4, 0, 0,
+            4, 2, 8, 512,
+            32, 64, 0, 0,
+        ]);
+        assert_eq!(expected_board, left_board);
+    }
+
+    #[test]
+    fn should_move_right() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            512, 8, 2, 4,
+            2, 0, 0, 0,
+            0, 4, 2, 2,
+            32, 32, 16, 16,
+        ]);
+
+        // When
+        let right_board = board.into_right();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            512, 8, 2, 4,
+            0, 0, 0, 2,
+            0, 0, 4, 4,
+            0, 0, 64, 32,
+        ]);
+        assert_eq!(expected_board, right_board);
+    }
+
+    #[test]
+    fn should_move_up() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 512, 16,
+            0, 2, 8, 16,
+            0, 4, 2, 32,
+            2, 0, 4, 32,
+        ]);
+
+        // When
+        let up_board = board.into_up();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            2, 4, 512, 32,
+            0, 4, 8, 64,
+            0, 0, 2, 0,
+            0, 0, 4, 0,
+        ]);
+        assert_eq!(expected_board, up_board);
+    }
+
+    #[test]
+    fn should_move_down() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 0, 512, 32,
+            0, 4, 8, 32,
+            0, 2, 2, 16,
+            0, 2, 4, 16,
+        ]);
+
+        // When
+        let down_board = board.into_down();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            0, 0, 512, 0,
+            0, 0, 8, 0,
+            0, 4, 2, 64,
+            2, 4, 4, 32,
+        ]);
+        assert_eq!(expected_board, down_board);
+    }
+
+    #[test]
+    fn should_move_with_high_values() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 0, 16384, 0,
+            0, 0, 16384, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let down_board = board.into_down();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 32768, 0,
+        ]);
+        assert_eq!(expected_board, down_board);
+    }
+
+    #[test]
+    fn should_move_with_score() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 0, 512, 32,
+            2, 4, 8, 32,
+            0, 2, 2, 16,
+            0, 2, 4, 16,
+        ]);
+
+        // When
+        let (down_board, score) = board.move_to_with_score(Direction::Down);
+
+        // Then
+        assert_eq!(board.move_to(Direction::Down), down_board);
+        assert_eq!(4 + 4 + 64 + 32, score);
+    }
+
+    #[test]
+    fn should_count_merges() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 2, 2,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            2, 4, 8, 16,
+        ]);
+
+        // When
+        let left_merges = board.merge_count(Direction::Left);
+        let up_merges = board.merge_count(Direction::Up);
+
+        // Then
+        assert_eq!(2, left_merges);
+        assert_eq!(1, up_merges);
+    }
+
+    #[test]
+    fn should_count_no_merge() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 8, 16,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let merges = board.merge_count(Direction::Left);
+
+        // Then
+        assert_eq!(0, merges);
+    }
+
+    #[test]
+    fn should_get_max_value() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board = vec![
+            0, 2, 0, 2048,
+            0, 256, 0, 512,
+            0, 0, 1024, 4,
+            8, 2, 16, 64
+        ];
+        let board = Board::from(vec_board);
+
+        // When
+        let max_value = board.max_value();
+
+        // Then
+        assert_eq!(2048, max_value);
+    }
+
+    #[test]
+    fn should_get_tile_sum() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board = vec![
+            0, 2, 0, 2048,
+            0, 256, 0, 512,
+            0, 0, 1024, 4,
+            8, 2, 16, 64
+        ];
+        let board = Board::from(vec_board);
+
+        // When
+        let tile_sum = board.tile_sum();
+
+        // Then
+        assert_eq!(3936, tile_sum);
+    }
+
+    #[test]
+    fn should_get_quadrant_sums() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 0, 2048,
+            0, 256, 0, 512,
+            0, 0, 1024, 4,
+            8, 2, 16, 64
+        ]);
+
+        // When
+        let quadrant_sums = board.quadrant_sums();
+
+        // Then
+        assert_eq!([258, 2560, 10, 1108], quadrant_sums);
+        assert_eq!(board.tile_sum(), quadrant_sums.iter().sum::<u32>());
+    }
+
+    #[cfg(any(feature = "static-tables", not(feature = "std")))]
+    #[test]
+    fn should_generate_static_moves_tables_equal_to_runtime_built_ones() {
+        // Given
+        let left_moves_table = crate::utils::build_left_moves_table();
+        let right_moves_table = crate::utils::build_right_moves_table();
+
+        // When
+        let static_left_moves_table = LEFT_MOVES_TABLE.to_vec();
+        let static_right_moves_table = RIGHT_MOVES_TABLE.to_vec();
+
+        // Then
+        assert_eq!(left_moves_table, static_left_moves_table);
+        assert_eq!(right_moves_table, static_right_moves_table);
+    }
+
+    #[test]
+    fn should_iterate_over_tiles() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 0, 0,
+            32768, 0, 0, 2,
+            0, 0, 16, 4,
+            8, 2, 16, 64
         ]);
-        assert_eq!(expected_board, down_board);
+
+        // When
+        let tiles: Vec<_> = board.tiles().collect();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_tiles = vec![
+            (0, 0), (1, 2), (2, 0), (3, 0),
+            (4, 32768), (5, 0), (6, 0), (7, 2),
+            (8, 0), (9, 0), (10, 16), (11, 4),
+            (12, 8), (13, 2), (14, 16), (15, 64),
+        ];
+        assert_eq!(expected_tiles, tiles);
     }
 
     #[test]
-    fn should_get_max_value() {
+    fn should_get_occupied_tiles() {
         // Given
         #[rustfmt::skip]
-        let vec_board = vec![
+        let board = Board::from(vec![
             0, 2, 0, 2048,
-            0, 256, 0, 512,
+            0, 0, 0, 0,
             0, 0, 1024, 4,
-            8, 2, 16, 64
+            8, 0, 0, 0
+        ]);
+
+        // When
+        let occupied_tiles: Vec<_> = board.occupied_tiles().collect();
+        let empty_board_occupied_tiles: Vec<_> = Board::default().occupied_tiles().collect();
+
+        // Then
+        let expected_tiles = vec![(1, 2), (3, 2048), (10, 1024), (11, 4), (12, 8)];
+        assert_eq!(expected_tiles, occupied_tiles);
+        assert!(empty_board_occupied_tiles.is_empty());
+    }
+
+    #[test]
+    fn should_get_spawn_successors() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 2, 4, 8,
+            16, 32, 64, 128,
+            256, 512, 1024, 2048,
+            0, 2, 4, 0,
+        ]);
+
+        // When
+        let successors = board.spawn_successors(0.1);
+
+        // Then
+        assert_eq!(2 * board.count_empty_tiles(), successors.len());
+        let total_proba: f32 = successors.iter().map(|(_, proba)| proba).sum();
+        assert!((total_proba - 1.).abs() < 1e-6);
+        assert_eq!((board.set_value(0, 2), 0.9 / 3.), successors[0]);
+        assert_eq!((board.set_value(15, 4), 0.1 / 3.), successors[5]);
+    }
+
+    #[test]
+    fn should_collect_tiles_into_board() {
+        // Given
+        let tiles = vec![(0, 2), (15, 2048)];
+
+        // When
+        let board: Board = tiles.into_iter().collect();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            2, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 2048,
+        ]);
+        assert_eq!(expected_board, board);
+        assert_eq!(board, board.occupied_tiles().collect());
+    }
+
+    #[test]
+    fn should_get_adjacent_pairs() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 8, 16,
+            0, 0, 0, 0,
+            32, 0, 64, 0,
+            0, 2, 0, 2,
+        ]);
+
+        // When
+        let pairs: Vec<(u8, u8)> = board.adjacent_pairs().collect();
+
+        // Then
+        #[rustfmt::skip]
+        let expected_pairs = vec![
+            (1, 2), (2, 3), (3, 4),
+            (0, 0), (0, 0), (0, 0),
+            (5, 0), (0, 6), (6, 0),
+            (0, 1), (1, 0), (0, 1),
+            (1, 0), (0, 5), (5, 0),
+            (2, 0), (0, 0), (0, 1),
+            (3, 0), (0, 6), (6, 0),
+            (4, 0), (0, 0), (0, 1),
         ];
-        let board = Board::from(vec_board);
+        assert_eq!(24, pairs.len());
+        assert_eq!(expected_pairs, pairs);
+    }
+
+    #[test]
+    fn should_set_row() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 8, 16,
+            0, 0, 0, 0,
+            32, 0, 64, 0,
+            0, 2, 0, 2,
+        ]);
 
         // When
-        let max_value = board.max_value();
+        let updated_board = board.set_row(2, 0x1B03);
 
         // Then
-        assert_eq!(2048, max_value);
+        let rows = board.rows();
+        assert_eq!([rows[0], rows[1], 0x1B03, rows[3]], updated_board.rows());
+    }
+
+    #[test]
+    fn should_set_column() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 8, 16,
+            0, 0, 0, 0,
+            32, 0, 64, 0,
+            0, 2, 0, 2,
+        ]);
+
+        // When
+        let updated_board = board.set_column(1, 0x1B03);
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board::from(vec![
+            2, 2, 8, 16,
+            0, 2048, 0, 0,
+            32, 0, 64, 0,
+            0, 8, 0, 2,
+        ]);
+        assert_eq!(expected_board, updated_board);
+    }
+
+    #[test]
+    fn should_check_if_board_is_full() {
+        // Given
+        #[rustfmt::skip]
+        let full_board = Board::from(vec![
+            2, 4, 8, 16,
+            4, 8, 16, 32,
+            2, 1024, 64, 128,
+            32768, 2, 4, 8,
+        ]);
+        let one_empty_board = full_board.set_value(9, 0);
+        let last_empty_board = full_board.set_value(15, 0);
+        let first_empty_board = full_board.set_value(0, 0);
+
+        // When / Then
+        assert!(full_board.is_full());
+        assert!(!one_empty_board.is_full());
+        assert!(!last_empty_board.is_full());
+        assert!(!first_empty_board.is_full());
+        assert!(!Board::default().is_full());
     }
 
     #[test]
@@ -767,6 +2279,54 @@ mod tests {
         assert_eq!(6, nb_empty_tiles);
     }
 
+    #[test]
+    fn should_know_number_of_empty_tiles() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board = vec![
+            0, 2, 0, 2048,
+            0, 256, 0, 512,
+            0, 0, 1024, 4,
+            8, 2, 16, 64
+        ];
+        let board = Board::from(vec_board);
+        let mut empty_tiles = board.empty_tiles_indices();
+
+        // When
+        let initial_len = empty_tiles.len();
+        empty_tiles.next();
+        let len_after_next = empty_tiles.len();
+
+        // Then
+        assert_eq!(6, initial_len);
+        assert_eq!(board.count_empty_tiles(), initial_len);
+        assert_eq!(5, len_after_next);
+        assert_eq!(16, Board::default().empty_tiles_indices().len());
+    }
+
+    #[test]
+    fn should_count_value() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board = vec![
+            0, 2, 0, 2048,
+            0, 16, 0, 512,
+            0, 0, 8, 4,
+            8, 2, 16, 2
+        ];
+        let board = Board::from(vec_board);
+
+        // When
+        let nb_2s = board.count_value(2);
+        let nb_16s = board.count_value(16);
+        let nb_1024s = board.count_value(1024);
+
+        // Then
+        assert_eq!(3, nb_2s);
+        assert_eq!(2, nb_16s);
+        assert_eq!(0, nb_1024s);
+    }
+
     #[test]
     fn should_count_distinct_tiles() {
         // Given
@@ -784,6 +2344,69 @@ mod tests {
         assert_eq!(7, distinct_tiles);
     }
 
+    #[test]
+    fn should_detect_game_over() {
+        // Given
+        #[rustfmt::skip]
+        let game_over_board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+        #[rustfmt::skip]
+        let full_board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 4,
+        ]);
+
+        // When
+        let is_game_over = game_over_board.is_game_over();
+        let is_full_board_game_over = full_board.is_game_over();
+
+        // Then
+        assert!(is_game_over);
+        assert!(!is_full_board_game_over);
+    }
+
+    #[test]
+    fn should_compute_minimum_score() {
+        // Given
+        let board = Board::default().set_value(5, 2048);
+        let board_of_4s = Board::from(vec![4; 16]);
+
+        // When
+        let minimum_score = board.minimum_score();
+        let minimum_score_of_4s = board_of_4s.minimum_score();
+
+        // Then
+        assert_eq!(20480, minimum_score);
+        assert_eq!(64, minimum_score_of_4s);
+    }
+
+    #[test]
+    fn should_diff_boards() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 0, 4, 8,
+            0, 0, 16, 16,
+            0, 0, 0, 0,
+            0, 0, 0, 2,
+        ]);
+        let other_board = board.set_value(6, 32);
+
+        // When
+        let diff = board.diff(other_board);
+        let empty_diff = board.diff(board);
+
+        // Then
+        assert_eq!(vec![(6, 16, 32)], diff);
+        assert!(empty_diff.is_empty());
+    }
+
     #[test]
     fn should_display_board_for_debug() {
         // Given
@@ -814,6 +2437,123 @@ mod tests {
         assert_eq!(expected_display, display);
     }
 
+    #[test]
+    fn should_render_board_in_ascii() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            8192, 32, 16384, 32768,
+            4096, 256, 0, 512,
+            2048, 128, 1024, 4,
+            8, 2, 16, 64
+        ]);
+        let options = RenderOptions {
+            color: false,
+            ascii_only: true,
+        };
+
+        // When
+        let rendered_board = board.render(options);
+
+        // Then
+        let expected_rendering = r#"
++-------+-------+-------+-------+
+|  8192 |    32 | 16384 | 32768 |
++-------+-------+-------+-------+
+|  4096 |   256 |       |   512 |
++-------+-------+-------+-------+
+|  2048 |   128 |  1024 |     4 |
++-------+-------+-------+-------+
+|     8 |     2 |    16 |    64 |
++-------+-------+-------+-------+
+"#;
+        assert_eq!(expected_rendering, rendered_board);
+    }
+
+    #[test]
+    fn should_render_plain_board_as_debug() {
+        // Given
+        let board = Board::default().set_value(3, 32768);
+
+        // When
+        let rendered_board = board.render(RenderOptions::default());
+
+        // Then
+        assert_eq!(format!("{:?}", board), rendered_board);
+        assert!(!rendered_board.contains('\u{1b}'));
+    }
+
+    #[cfg(not(feature = "cli"))]
+    #[test]
+    fn should_display_plain_ascii_board_without_cli() {
+        // Given
+        let board = Board::default().set_value(3, 32768);
+
+        // When
+        let displayed_board = format!("{}", board);
+
+        // Then
+        assert!(displayed_board.is_ascii());
+        assert!(displayed_board.contains("32768"));
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn should_render_colorized_board() {
+        // Given
+        let board = Board::default().set_value(3, 32768);
+        let color_32768 = format!("{}", get_color(15));
+
+        // When
+        let unicode_rendering = board.render(RenderOptions {
+            color: true,
+            ascii_only: false,
+        });
+        let ascii_rendering = board.render(RenderOptions {
+            color: true,
+            ascii_only: true,
+        });
+
+        // Then
+        assert!(unicode_rendering.contains(&color_32768));
+        assert!(unicode_rendering.contains('╔'));
+        assert!(ascii_rendering.contains(&color_32768));
+        assert!(ascii_rendering.is_ascii());
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn should_get_color_for_any_exponent() {
+        // Given
+        let exponents = 0..=17;
+
+        // When
+        let colors: Vec<_> = exponents.map(get_color).collect();
+
+        // Then
+        assert_eq!(18, colors.len());
+        assert_eq!(
+            format!("{}", color::Fg(color::Rgb(237, 194, 46))),
+            format!("{}", colors[11])
+        );
+        for exponent in 12..17 {
+            assert_ne!(
+                format!("{}", colors[exponent]),
+                format!("{}", colors[exponent + 1])
+            );
+        }
+        for exponent in 1..16 {
+            let board = Board::default().set_value_by_exponent(0, exponent);
+            let colorized_tile = format!(
+                "{}{}{}",
+                colors[exponent as usize],
+                board.get_value(0),
+                color::Fg(color::Reset)
+            );
+            assert!(format!("{}", board).contains(&colorized_tile));
+        }
+    }
+
     #[test]
     fn should_display_board() {
         // Given
@@ -829,4 +2569,65 @@ mod tests {
         // When / Then
         format!("{}", board);
     }
+
+    #[test]
+    fn should_get_legal_move_boards() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 0, 0, 0,
+            4, 0, 0, 0,
+            8, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let legal_move_boards = board.legal_move_boards();
+
+        // Then
+        #[rustfmt::skip]
+        let right_board = Board::from(vec![
+            0, 0, 0, 2,
+            0, 0, 0, 4,
+            0, 0, 0, 8,
+            0, 0, 0, 0,
+        ]);
+        #[rustfmt::skip]
+        let down_board = Board::from(vec![
+            0, 0, 0, 0,
+            2, 0, 0, 0,
+            4, 0, 0, 0,
+            8, 0, 0, 0,
+        ]);
+        let expected_move_boards = vec![
+            (Direction::Right, right_board),
+            (Direction::Down, down_board),
+        ];
+        assert_eq!(expected_move_boards, legal_move_boards);
+    }
+
+    #[test]
+    fn should_apply_move_and_spawn_like_game() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 4,
+            0, 0, 8, 0,
+            0, 4, 0, 0,
+            0, 0, 0, 2,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).seed(3).build();
+        game.step(Direction::Left);
+        let spawn = *game.spawn_log().spawns().last().unwrap();
+
+        // When
+        let applied_board = board.apply(Direction::Left, Some(spawn));
+
+        // Then
+        assert_eq!(game.board, applied_board);
+        assert_eq!(
+            board.move_to(Direction::Left),
+            board.apply(Direction::Left, None)
+        );
+    }
 }
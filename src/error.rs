@@ -0,0 +1,48 @@
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+/// Error returned by the fallible operations of the crate
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+}
+
+/// The different kinds of `Error`
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ErrorKind {
+    /// The representation of the board is not valid, e.g. it does not contain 16 tiles
+    InvalidBoardRepr,
+    /// The value of a square is not a power of 2 between `2` and `2^15`, nor `0`, or more
+    /// generally not a valid tile value of the `TileScheme` in use
+    InvalidSquareValue(u16),
+    /// The name of a direction is not recognized
+    InvalidDirection,
+    /// The `BoardEvaluator` produced a NaN or infinite evaluation
+    InvalidEvaluation,
+    /// The outcomes of a spawn distribution are empty, have an exponent outside of `1..=15`, or
+    /// have weights which do not sum to a positive finite number
+    InvalidSpawnDistribution,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -1,17 +1,97 @@
 use crate::board::Board;
-use std::cmp::{min, Ordering};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{min, Ordering};
 
 /// Evaluate a `Board` by mapping it to a number. The higher the number, the better the board
 /// state.
 pub trait BoardEvaluator {
     fn evaluate(&self, board: Board) -> f32;
     fn gameover_penalty(&self) -> f32;
+
+    /// Evaluates each of the provided boards, in order
+    fn evaluate_batch(&self, boards: &[Board]) -> Vec<f32> {
+        boards.iter().map(|board| self.evaluate(*board)).collect()
+    }
 }
 
 /// Evaluate a `Board` by evaluating independently each row and column and summing the results
 pub trait RowColumnEvaluator {
     fn evaluate_row(&self, row: u16) -> f32;
     fn gameover_penalty(&self) -> f32;
+
+    /// Computes statistics of the evaluations of all the possible rows
+    fn get_statistics(&self) -> EvaluatorStats {
+        let values: Vec<f32> = (0..(u16::MAX as usize + 1))
+            .map(|row| self.evaluate_row(row as u16))
+            .collect();
+        let nb_values = values.len() as f64;
+        let mean = values.iter().map(|v| *v as f64).sum::<f64>() / nb_values;
+        let variance = values
+            .iter()
+            .map(|v| {
+                let diff = *v as f64 - mean;
+                diff * diff
+            })
+            .sum::<f64>()
+            / nb_values;
+        EvaluatorStats {
+            min: values.iter().cloned().fold(f32::INFINITY, f32::min),
+            max: values.iter().cloned().fold(f32::NEG_INFINITY, f32::max),
+            mean: mean as f32,
+            standard_dev: libm::sqrt(variance) as f32,
+        }
+    }
+
+    /// Counts the evaluations of all the possible rows in `bins` buckets of equal width,
+    /// between the min and the max evaluations
+    /// The max evaluation is counted in the last bucket, and all the evaluations are counted in
+    /// the first one when they are equal.
+    fn value_histogram(&self, bins: usize) -> Vec<u32> {
+        let mut histogram = vec![0; bins];
+        if bins == 0 {
+            return histogram;
+        }
+        let values: Vec<f32> = (0..(u16::MAX as usize + 1))
+            .map(|row| self.evaluate_row(row as u16))
+            .collect();
+        let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+        let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+        let bin_width = (max - min) / bins as f32;
+        for value in values {
+            let bin = if bin_width > 0. {
+                (((value - min) / bin_width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            histogram[bin] += 1;
+        }
+        histogram
+    }
+
+    /// Returns the weight which scales the range of the evaluations of the rows, i.e. the
+    /// difference between the max and the min, to `target_scale`
+    /// This allows to combine evaluators whose outputs have different orders of magnitude. The
+    /// weight is 1 when all the rows have the same evaluation.
+    fn suggested_weight(&self, target_scale: f32) -> f32 {
+        let stats = self.get_statistics();
+        let range = stats.max - stats.min;
+        if range == 0. {
+            return 1.;
+        }
+        target_scale / range
+    }
+}
+
+/// Statistics of the evaluations of all the possible rows by a `RowColumnEvaluator`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct EvaluatorStats {
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    /// population standard deviation
+    pub standard_dev: f32,
 }
 
 impl<T> BoardEvaluator for T
@@ -45,13 +125,24 @@ impl PrecomputedBoardEvaluator {
     where
         T: RowColumnEvaluator,
     {
-        let row_cache = (0..(std::u16::MAX as usize + 1))
-            .map(|row| evaluator.evaluate_row(row as u16))
-            .collect();
-        Self {
-            row_cache,
-            gameover_penalty: evaluator.gameover_penalty(),
-        }
+        let mut precomputed_evaluator = Self {
+            row_cache: Vec::with_capacity(u16::MAX as usize + 1),
+            gameover_penalty: 0.,
+        };
+        precomputed_evaluator.rebuild_cache(&evaluator);
+        precomputed_evaluator
+    }
+
+    /// Recomputes the cached values using the provided evaluator, without reallocating the cache
+    /// This is typically useful after having changed the weights of a `CombinedBoardEvaluator`.
+    pub fn rebuild_cache<T>(&mut self, evaluator: &T)
+    where
+        T: RowColumnEvaluator,
+    {
+        self.row_cache.clear();
+        self.row_cache
+            .extend((0..(u16::MAX as usize + 1)).map(|row| evaluator.evaluate_row(row as u16)));
+        self.gameover_penalty = evaluator.gameover_penalty();
     }
 }
 
@@ -71,6 +162,46 @@ impl CombinedBoardEvaluator {
         self.evaluators.push((Box::new(evaluator), weight));
         self
     }
+
+    /// Returns the weights of the combined evaluators, in the order in which they were combined
+    pub fn weights(&self) -> Vec<f32> {
+        self.evaluators.iter().map(|(_, weight)| *weight).collect()
+    }
+
+    /// Updates the weight of the evaluator at position `index`, evaluators being indexed in the
+    /// order in which they were combined
+    /// A `PrecomputedBoardEvaluator` built from this evaluator must then be updated with
+    /// `PrecomputedBoardEvaluator::rebuild_cache`.
+    pub fn set_weight(&mut self, index: usize, weight: f32) {
+        self.evaluators[index].1 = weight;
+    }
+
+    /// Returns the weighted evaluation of the board by each of the combined evaluators, in the
+    /// order in which they were combined
+    /// These contributions sum up to the evaluation of the board.
+    pub fn evaluate_breakdown(&self, board: Board) -> Vec<f32> {
+        self.evaluators
+            .iter()
+            .map(|(evaluator, weight)| {
+                let evaluation: f32 = board
+                    .rows()
+                    .iter()
+                    .chain(board.columns().iter())
+                    .map(|row| evaluator.evaluate_row(*row))
+                    .sum();
+                weight * evaluation
+            })
+            .collect()
+    }
+
+    /// Converts the evaluator into a `PrecomputedBoardEvaluator`, in which the weighted sum of
+    /// the combined evaluators is cached for every possible row
+    /// Building the cache requires to evaluate the 65536 possible rows with each combined
+    /// evaluator once, after which evaluating a board only costs 8 lookups regardless of the
+    /// number of combined evaluators.
+    pub fn precompute(self) -> PrecomputedBoardEvaluator {
+        PrecomputedBoardEvaluator::new(self)
+    }
 }
 
 impl RowColumnEvaluator for CombinedBoardEvaluator {
@@ -102,6 +233,90 @@ impl BoardEvaluator for PrecomputedBoardEvaluator {
     fn gameover_penalty(&self) -> f32 {
         self.gameover_penalty
     }
+
+    /// Evaluates the boards in a single loop over the row cache, avoiding a dynamic call to
+    /// `evaluate` per board when the evaluator is used as a trait object
+    fn evaluate_batch(&self, boards: &[Board]) -> Vec<f32> {
+        let row_cache = self.row_cache.as_slice();
+        boards
+            .iter()
+            .map(|board| {
+                board
+                    .rows()
+                    .iter()
+                    .chain(board.columns().iter())
+                    .map(|row| row_cache[*row as usize])
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+/// `BoardEvaluator` implementation which combines multiple board evaluators by summing their
+/// weighted evaluations
+/// Contrary to `CombinedBoardEvaluator`, any `BoardEvaluator` can be combined, which allows to
+/// mix a `PrecomputedBoardEvaluator` with evaluators which are not separable into rows and
+/// columns.
+#[derive(Default)]
+pub struct SummedBoardEvaluator {
+    /// evaluators along with their weight
+    evaluators: Vec<(Box<dyn BoardEvaluator>, f32)>,
+}
+
+impl SummedBoardEvaluator {
+    pub fn combine<T>(mut self, evaluator: T, weight: f32) -> Self
+    where
+        T: BoardEvaluator + 'static,
+    {
+        self.evaluators.push((Box::new(evaluator), weight));
+        self
+    }
+}
+
+impl BoardEvaluator for SummedBoardEvaluator {
+    fn evaluate(&self, board: Board) -> f32 {
+        self.evaluators
+            .iter()
+            .map(|(evaluator, weight)| weight * evaluator.evaluate(board))
+            .sum()
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.evaluators
+            .iter()
+            .map(|(evaluator, _)| evaluator.gameover_penalty())
+            .sum()
+    }
+}
+
+/// `BoardEvaluator` implementation which sums a `RowColumnEvaluator`, whose row evaluations are
+/// pre-computed, and a `BoardEvaluator` which is not separable into rows and columns
+pub struct HybridEvaluator {
+    row_column_part: PrecomputedBoardEvaluator,
+    board_part: Box<dyn BoardEvaluator>,
+}
+
+impl HybridEvaluator {
+    pub fn new<R, B>(row_column_evaluator: R, board_evaluator: B) -> Self
+    where
+        R: RowColumnEvaluator,
+        B: BoardEvaluator + 'static,
+    {
+        Self {
+            row_column_part: PrecomputedBoardEvaluator::new(row_column_evaluator),
+            board_part: Box::new(board_evaluator),
+        }
+    }
+}
+
+impl BoardEvaluator for HybridEvaluator {
+    fn evaluate(&self, board: Board) -> f32 {
+        self.row_column_part.evaluate(board) + self.board_part.evaluate(board)
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.row_column_part.gameover_penalty() + self.board_part.gameover_penalty()
+    }
 }
 
 /// A simple implementation of `BoardEvaluator` which evaluates a board by simply computing
@@ -175,10 +390,51 @@ impl RowColumnEvaluator for AlignmentEvaluator {
     }
 }
 
+/// `BoardEvaluator` implementation which penalizes the differences between the exponents of
+/// adjacent non-empty tiles
+pub struct SmoothnessEvaluator {
+    pub gameover_penalty: f32,
+    pub power: u32,
+}
+
+impl Default for SmoothnessEvaluator {
+    fn default() -> Self {
+        Self {
+            gameover_penalty: 0.,
+            power: 1,
+        }
+    }
+}
+
+impl RowColumnEvaluator for SmoothnessEvaluator {
+    fn evaluate_row(&self, row: u16) -> f32 {
+        let mut row = row;
+        let mut right_value = row & 0b1111;
+        row >>= 4;
+        let mut penalty: u32 = 0;
+        for _ in 1..4 {
+            let value = row & 0b1111;
+            if value != 0 && right_value != 0 {
+                let diff = (value as i32 - right_value as i32).unsigned_abs();
+                penalty = penalty.saturating_add(diff.saturating_pow(self.power));
+            }
+            right_value = value;
+            row >>= 4;
+        }
+        -(penalty as f32)
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.gameover_penalty
+    }
+}
+
 /// `BoardEvaluator` implementation which computes inversions on rows and columns, add them, and
 /// then takes the negative value of it.
 /// Inversions are computed by summing the differences between exponents which appear in the
 /// wrong order in each row / column
+/// The powers of the exponents are computed on `u64` and the inversions saturate, which keeps
+/// the evaluation exact for a `monotonicity_power` up to 16.
 pub struct MonotonicityEvaluator {
     pub gameover_penalty: f32,
     pub monotonicity_power: u32,
@@ -193,21 +449,35 @@ impl Default for MonotonicityEvaluator {
     }
 }
 
+impl MonotonicityEvaluator {
+    /// Returns the difference between the powers of the exponents `high > low`, which saturates
+    /// instead of vanishing when the powers overflow
+    fn powers_diff(&self, high: u64, low: u64) -> u64 {
+        match (
+            high.checked_pow(self.monotonicity_power),
+            low.checked_pow(self.monotonicity_power),
+        ) {
+            (Some(high_power), Some(low_power)) => high_power - low_power,
+            _ => u64::MAX,
+        }
+    }
+}
+
 impl RowColumnEvaluator for MonotonicityEvaluator {
     fn evaluate_row(&self, row: u16) -> f32 {
-        let mut left_value: u32 = (row >> 12) as u32;
-        let mut left_right_inversions = 0;
-        let mut right_left_inversions = 0;
+        let mut left_value: u64 = (row >> 12) as u64;
+        let mut left_right_inversions: u64 = 0;
+        let mut right_left_inversions: u64 = 0;
         for col in 1..4 {
-            let v: u32 = ((row >> (4 * (3 - col))) & 0b1111) as u32;
+            let v: u64 = ((row >> (4 * (3 - col))) & 0b1111) as u64;
             match v.cmp(&left_value) {
                 Ordering::Less => {
-                    left_right_inversions +=
-                        left_value.pow(self.monotonicity_power) - v.pow(self.monotonicity_power);
+                    left_right_inversions =
+                        left_right_inversions.saturating_add(self.powers_diff(left_value, v));
                 }
                 Ordering::Greater => {
-                    right_left_inversions +=
-                        v.pow(self.monotonicity_power) - left_value.pow(self.monotonicity_power);
+                    right_left_inversions =
+                        right_left_inversions.saturating_add(self.powers_diff(v, left_value));
                 }
                 Ordering::Equal => {}
             }
@@ -221,6 +491,172 @@ impl RowColumnEvaluator for MonotonicityEvaluator {
     }
 }
 
+/// `BoardEvaluator` implementation which sums the exponents of the tiles weighted by their
+/// position, in order to bias the largest tiles towards a corner
+/// The default weights follow a snake shaped path starting from the top-left corner.
+pub struct SnakeEvaluator {
+    pub gameover_penalty: f32,
+    /// weights of the 16 positions, indexed like the tiles of the `Board`
+    pub weights: [f32; 16],
+}
+
+impl Default for SnakeEvaluator {
+    fn default() -> Self {
+        Self {
+            gameover_penalty: 0.,
+            #[rustfmt::skip]
+            weights: [
+                15., 14., 13., 12.,
+                8., 9., 10., 11.,
+                7., 6., 5., 4.,
+                0., 1., 2., 3.,
+            ],
+        }
+    }
+}
+
+impl BoardEvaluator for SnakeEvaluator {
+    fn evaluate(&self, board: Board) -> f32 {
+        board
+            .into_iter()
+            .zip(self.weights.iter())
+            .map(|(exponent, weight)| exponent as f32 * weight)
+            .sum()
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.gameover_penalty
+    }
+}
+
+/// `BoardEvaluator` implementation which rewards a global gradient of the tiles towards one of
+/// the four corners
+/// The exponents of the tiles are weighted by their proximity to a corner, with weights going
+/// from 3 in the corner to -3 in the opposite one, and the best of the four corners is kept.
+pub struct GradientEvaluator {
+    pub gameover_penalty: f32,
+}
+
+impl Default for GradientEvaluator {
+    fn default() -> Self {
+        Self {
+            gameover_penalty: 0.,
+        }
+    }
+}
+
+impl BoardEvaluator for GradientEvaluator {
+    fn evaluate(&self, board: Board) -> f32 {
+        let mut corner_scores = [0.; 4];
+        for (idx, exponent) in board.into_iter().enumerate() {
+            let (row, col) = ((idx / 4) as f32, (idx % 4) as f32);
+            let exponent = exponent as f32;
+            corner_scores[0] += exponent * (3. - row - col);
+            corner_scores[1] += exponent * (col - row);
+            corner_scores[2] += exponent * (row - col);
+            corner_scores[3] += exponent * (row + col - 3.);
+        }
+        corner_scores
+            .iter()
+            .cloned()
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.gameover_penalty
+    }
+}
+
+/// Returns how close the board is to game over, between `0` and `1`
+/// The level decreases linearly with the number of empty tiles plus the number of pairs of
+/// adjacent tiles which can be merged, from `1` when only one of them is left (or none) to `0`
+/// when there are 16 or more.
+pub fn danger_level(board: Board) -> f32 {
+    let nb_mergeable_pairs = board
+        .adjacent_pairs()
+        .filter(|(left, right)| left == right && *left != 0)
+        .count();
+    let nb_options = board.count_empty_tiles() + nb_mergeable_pairs;
+    1. - min(nb_options.saturating_sub(1), 15) as f32 / 15.
+}
+
+/// `BoardEvaluator` implementation which rewards having the maximum tile in a corner
+/// The evaluation is `corner_bonus` when one of the maximum tiles is in a corner,
+/// `centered_penalty` when they are all in the four central tiles, and `0` otherwise.
+pub struct MaxTileCornerEvaluator {
+    pub gameover_penalty: f32,
+    pub corner_bonus: f32,
+    pub centered_penalty: f32,
+}
+
+impl Default for MaxTileCornerEvaluator {
+    fn default() -> Self {
+        Self {
+            gameover_penalty: 0.,
+            corner_bonus: 1.,
+            centered_penalty: -1.,
+        }
+    }
+}
+
+impl BoardEvaluator for MaxTileCornerEvaluator {
+    fn evaluate(&self, board: Board) -> f32 {
+        let max_exponent = board.into_iter().max().unwrap();
+        let max_indices = board
+            .into_iter()
+            .enumerate()
+            .filter(|(_, exponent)| *exponent == max_exponent)
+            .map(|(idx, _)| idx);
+        let mut is_centered = true;
+        for idx in max_indices {
+            if [0, 3, 12, 15].contains(&idx) {
+                return self.corner_bonus;
+            }
+            if ![5, 6, 9, 10].contains(&idx) {
+                is_centered = false;
+            }
+        }
+        if is_centered {
+            self.centered_penalty
+        } else {
+            0.
+        }
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.gameover_penalty
+    }
+}
+
+/// `BoardEvaluator` implementation which rewards the merges that can be performed immediately
+/// Each pair of equal, non-empty, adjacent tiles contributes the value of its tiles, so that
+/// merges of large tiles are worth more.
+pub struct MergePotentialEvaluator {
+    pub gameover_penalty: f32,
+}
+
+impl Default for MergePotentialEvaluator {
+    fn default() -> Self {
+        Self {
+            gameover_penalty: 0.,
+        }
+    }
+}
+
+impl BoardEvaluator for MergePotentialEvaluator {
+    fn evaluate(&self, board: Board) -> f32 {
+        board
+            .adjacent_pairs()
+            .filter(|(left, right)| left == right && *left != 0)
+            .map(|(exponent, _)| (1u32 << exponent) as f32)
+            .sum()
+    }
+
+    fn gameover_penalty(&self) -> f32 {
+        self.gameover_penalty
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +681,81 @@ mod tests {
         assert_eq!(4., evaluator.evaluate_row(board.rows()[1]));
     }
 
+    #[test]
+    fn test_constant_evaluator_statistics() {
+        // Given
+        struct ConstantEvaluator;
+        impl RowColumnEvaluator for ConstantEvaluator {
+            fn evaluate_row(&self, _row: u16) -> f32 {
+                3.
+            }
+
+            fn gameover_penalty(&self) -> f32 {
+                0.
+            }
+        }
+
+        // When
+        let stats = ConstantEvaluator.get_statistics();
+
+        // Then
+        let expected_stats = EvaluatorStats {
+            min: 3.,
+            max: 3.,
+            mean: 3.,
+            standard_dev: 0.,
+        };
+        assert_eq!(expected_stats, stats);
+    }
+
+    #[test]
+    fn test_empty_tile_evaluator_statistics() {
+        // Given
+        let evaluator = EmptyTileEvaluator::default();
+
+        // When
+        let stats = evaluator.get_statistics();
+
+        // Then
+        // the number of empty tiles in a row follows a binomial distribution B(4, 1/16)
+        let expected_standard_dev = (4f32 * (1. / 16.) * (15. / 16.)).sqrt();
+        assert_eq!(0., stats.min);
+        assert_eq!(4., stats.max);
+        assert!((0.25 - stats.mean).abs() < 1e-6);
+        assert!((expected_standard_dev - stats.standard_dev).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty_tile_evaluator_value_histogram() {
+        // Given
+        let evaluator = EmptyTileEvaluator {
+            gameover_penalty: 0.,
+            power: 1,
+        };
+
+        // When
+        let histogram = evaluator.value_histogram(5);
+
+        // Then
+        // a row has k empty tiles in C(4, k) * 15^(4 - k) cases
+        assert_eq!(65536, histogram.iter().sum::<u32>());
+        assert_eq!(vec![50625, 13500, 1350, 60, 1], histogram);
+    }
+
+    #[test]
+    fn test_suggested_weight() {
+        // Given
+        let evaluator = MonotonicityEvaluator::default();
+        let stats = evaluator.get_statistics();
+
+        // When
+        let weight = evaluator.suggested_weight(10.);
+
+        // Then
+        let scaled_range = weight * (stats.max - stats.min);
+        assert!((10. - scaled_range).abs() < 1e-3);
+    }
+
     #[test]
     fn test_alignment_evaluator() {
         // Given
@@ -268,6 +779,29 @@ mod tests {
         assert_eq!(0., evaluator.evaluate_row(rows[2]));
     }
 
+    #[test]
+    fn test_smoothness_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board = vec![
+            4, 4, 4, 4,
+            2, 64, 4, 0,
+            0, 0, 0, 0,
+            8, 2, 16, 64
+        ];
+        let board = Board::from(vec_board);
+        let evaluator = SmoothnessEvaluator {
+            gameover_penalty: 0.,
+            power: 2,
+        };
+
+        // When / Then
+        let rows = board.rows();
+        assert_eq!(0., evaluator.evaluate_row(rows[0]));
+        assert_eq!(-41., evaluator.evaluate_row(rows[1]));
+        assert_eq!(0., evaluator.evaluate_row(rows[2]));
+    }
+
     #[test]
     fn test_monotonicity_evaluator() {
         // Given
@@ -352,4 +886,439 @@ mod tests {
         assert_eq!(-9. + 2. * 4., evaluation_1);
         assert_eq!(-15. + 2. * 1., evaluation_2);
     }
+
+    #[test]
+    fn test_evaluate_batch() {
+        // Given
+        #[rustfmt::skip]
+        let boards = [
+            Board::from(vec![
+                2, 4, 2, 4,
+                8, 0, 0, 512,
+                1024, 2, 16, 0,
+                8, 2, 16, 64,
+            ]),
+            Board::from(vec![
+                0, 0, 0, 0,
+                0, 2, 0, 0,
+                0, 0, 0, 0,
+                0, 0, 4, 0,
+            ]),
+            Board::default(),
+        ];
+        let evaluator = MonotonicityEvaluator {
+            gameover_penalty: 0.,
+            monotonicity_power: 2,
+        };
+        let precomputed_evaluator = PrecomputedBoardEvaluator::new(MonotonicityEvaluator {
+            gameover_penalty: 0.,
+            monotonicity_power: 2,
+        });
+
+        // When
+        let batch_evaluations = evaluator.evaluate_batch(&boards);
+        let precomputed_batch_evaluations = precomputed_evaluator.evaluate_batch(&boards);
+
+        // Then
+        let expected_evaluations: Vec<_> = boards.iter().map(|b| evaluator.evaluate(*b)).collect();
+        assert_eq!(expected_evaluations, batch_evaluations);
+        assert_eq!(expected_evaluations, precomputed_batch_evaluations);
+    }
+
+    #[test]
+    fn test_precomputed_combined_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            8, 0, 0, 512,
+            1024, 2, 16, 0,
+            8, 2, 16, 64,
+        ]);
+        let evaluator = CombinedBoardEvaluator::default()
+            .combine(
+                EmptyTileEvaluator {
+                    gameover_penalty: -10.,
+                    power: 2,
+                },
+                2.0,
+            )
+            .combine(
+                MonotonicityEvaluator {
+                    gameover_penalty: -20.,
+                    monotonicity_power: 2,
+                },
+                1.0,
+            );
+        let direct_evaluation = BoardEvaluator::evaluate(&evaluator, board);
+
+        // When
+        let precomputed_evaluator = evaluator.precompute();
+
+        // Then
+        assert_eq!(direct_evaluation, precomputed_evaluator.evaluate(board));
+        assert_eq!(-30., precomputed_evaluator.gameover_penalty());
+    }
+
+    #[test]
+    fn test_combined_evaluator_breakdown() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            8, 0, 0, 512,
+            1024, 2, 16, 0,
+            8, 2, 16, 64,
+        ]);
+        let evaluator = CombinedBoardEvaluator::default()
+            .combine(EmptyTileEvaluator::default(), 2.0)
+            .combine(MonotonicityEvaluator::default(), 1.0);
+
+        // When
+        let breakdown = evaluator.evaluate_breakdown(board);
+
+        // Then
+        let expected_breakdown = vec![
+            2. * EmptyTileEvaluator::default().evaluate(board),
+            MonotonicityEvaluator::default().evaluate(board),
+        ];
+        assert_eq!(expected_breakdown, breakdown);
+        assert_eq!(evaluator.evaluate(board), breakdown.iter().sum::<f32>());
+    }
+
+    #[test]
+    fn test_combined_evaluator_set_weight() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            8, 0, 0, 512,
+            1024, 2, 16, 0,
+            8, 2, 16, 64,
+        ]);
+        let mut evaluator = CombinedBoardEvaluator::default()
+            .combine(EmptyTileEvaluator::default(), 2.0)
+            .combine(MonotonicityEvaluator::default(), 1.0);
+        let mut precomputed_evaluator = PrecomputedBoardEvaluator::new(
+            CombinedBoardEvaluator::default()
+                .combine(EmptyTileEvaluator::default(), 2.0)
+                .combine(MonotonicityEvaluator::default(), 1.0),
+        );
+
+        // When
+        evaluator.set_weight(0, 5.0);
+        precomputed_evaluator.rebuild_cache(&evaluator);
+
+        // Then
+        let row = board.rows()[1];
+        let expected_evaluation = 5. * 2. + MonotonicityEvaluator::default().evaluate_row(row);
+        assert_eq!(vec![5., 1.], evaluator.weights());
+        assert_eq!(expected_evaluation, evaluator.evaluate_row(row));
+        assert_eq!(
+            evaluator.evaluate(board),
+            precomputed_evaluator.evaluate(board)
+        );
+    }
+
+    #[test]
+    fn test_snake_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let corner_board = Board::from(vec![
+            256, 4, 2, 0,
+            2, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        #[rustfmt::skip]
+        let centered_board = Board::from(vec![
+            0, 4, 2, 0,
+            2, 256, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let evaluator = SnakeEvaluator::default();
+
+        // When
+        let corner_evaluation = evaluator.evaluate(corner_board);
+        let centered_evaluation = evaluator.evaluate(centered_board);
+
+        // Then
+        assert_eq!(15. * 8. + 14. * 2. + 13. + 8., corner_evaluation);
+        assert!(corner_evaluation > centered_evaluation);
+    }
+
+    #[test]
+    fn test_hybrid_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let boards = [
+            Board::from(vec![
+                2, 4, 2, 4,
+                8, 0, 0, 512,
+                1024, 2, 16, 0,
+                8, 2, 16, 64,
+            ]),
+            Board::from(vec![
+                0, 0, 0, 2,
+                0, 0, 0, 0,
+                0, 4, 0, 0,
+                0, 0, 0, 0,
+            ]),
+            Board::from(vec![
+                2048, 1024, 512, 256,
+                8, 16, 32, 64,
+                4, 2, 0, 0,
+                0, 0, 0, 0,
+            ]),
+        ];
+        let evaluator = HybridEvaluator::new(
+            MonotonicityEvaluator::default(),
+            GradientEvaluator::default(),
+        );
+
+        // When
+        let evaluations: Vec<_> = boards.iter().map(|b| evaluator.evaluate(*b)).collect();
+
+        // Then
+        let expected_evaluations: Vec<_> = boards
+            .iter()
+            .map(|b| {
+                MonotonicityEvaluator::default().evaluate(*b)
+                    + GradientEvaluator::default().evaluate(*b)
+            })
+            .collect();
+        assert_eq!(expected_evaluations, evaluations);
+    }
+
+    #[test]
+    fn test_gradient_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let gradient_board = Board::from(vec![
+            2, 4, 8, 16,
+            4, 8, 16, 32,
+            8, 16, 32, 64,
+            16, 32, 64, 128,
+        ]);
+        #[rustfmt::skip]
+        let shuffled_board = Board::from(vec![
+            32, 4, 128, 16,
+            4, 64, 16, 2,
+            8, 16, 8, 64,
+            16, 32, 4, 32,
+        ]);
+        let evaluator = GradientEvaluator::default();
+
+        // When
+        let gradient_evaluation = evaluator.evaluate(gradient_board);
+        let shuffled_evaluation = evaluator.evaluate(shuffled_board);
+
+        // Then
+        assert_eq!(40., gradient_evaluation);
+        assert!(gradient_evaluation > shuffled_evaluation);
+    }
+
+    #[test]
+    fn test_summed_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            8, 0, 0, 512,
+            1024, 2, 16, 0,
+            8, 2, 16, 64,
+        ]);
+        let evaluator = SummedBoardEvaluator::default()
+            .combine(
+                PrecomputedBoardEvaluator::new(MonotonicityEvaluator::default()),
+                1.0,
+            )
+            .combine(SnakeEvaluator::default(), 10.0);
+
+        // When
+        let evaluation = evaluator.evaluate(board);
+
+        // Then
+        let expected_evaluation = MonotonicityEvaluator::default().evaluate(board)
+            + 10. * SnakeEvaluator::default().evaluate(board);
+        assert_eq!(expected_evaluation, evaluation);
+    }
+
+    #[test]
+    fn test_max_tile_corner_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let corner_board = Board::from(vec![
+            0, 4, 2, 0,
+            2, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 256,
+        ]);
+        #[rustfmt::skip]
+        let edge_board = Board::from(vec![
+            0, 4, 2, 0,
+            2, 0, 0, 0,
+            256, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        #[rustfmt::skip]
+        let centered_board = Board::from(vec![
+            0, 4, 2, 0,
+            2, 0, 0, 0,
+            0, 0, 256, 0,
+            0, 0, 0, 0,
+        ]);
+        let evaluator = MaxTileCornerEvaluator {
+            gameover_penalty: 0.,
+            corner_bonus: 10.,
+            centered_penalty: -5.,
+        };
+
+        // When
+        let corner_evaluation = evaluator.evaluate(corner_board);
+        let edge_evaluation = evaluator.evaluate(edge_board);
+        let centered_evaluation = evaluator.evaluate(centered_board);
+
+        // Then
+        assert_eq!(10., corner_evaluation);
+        assert_eq!(0., edge_evaluation);
+        assert_eq!(-5., centered_evaluation);
+    }
+
+    #[test]
+    fn test_merge_potential_evaluator() {
+        // Given
+        #[rustfmt::skip]
+        let large_merges_board = Board::from(vec![
+            256, 256, 2, 0,
+            8, 0, 0, 0,
+            256, 0, 0, 0,
+            256, 0, 0, 0,
+        ]);
+        #[rustfmt::skip]
+        let small_merges_board = Board::from(vec![
+            4, 4, 2, 0,
+            8, 0, 0, 0,
+            4, 0, 0, 0,
+            4, 0, 0, 0,
+        ]);
+        let evaluator = MergePotentialEvaluator::default();
+
+        // When
+        let large_merges_evaluation = evaluator.evaluate(large_merges_board);
+        let small_merges_evaluation = evaluator.evaluate(small_merges_board);
+
+        // Then
+        assert_eq!(512., large_merges_evaluation);
+        assert_eq!(8., small_merges_evaluation);
+        assert!(large_merges_evaluation > small_merges_evaluation);
+    }
+
+    #[test]
+    fn test_monotonicity_evaluator_with_high_power() {
+        // Given
+        let evaluator = MonotonicityEvaluator {
+            gameover_penalty: 0.,
+            monotonicity_power: 20,
+        };
+        let row =
+            Board::from(vec![2, 32768, 2, 32768, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).rows()[0];
+
+        // When
+        let evaluation = evaluator.evaluate_row(row);
+
+        // Then
+        assert!(evaluation.is_finite());
+        assert!(evaluation < 0.);
+    }
+
+    #[test]
+    fn test_monotonicity_evaluator_keeps_inversions_of_saturated_powers() {
+        // Given
+        let evaluator = MonotonicityEvaluator {
+            gameover_penalty: 0.,
+            monotonicity_power: 20,
+        };
+        // both 14^20 and 15^20 overflow a u64
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            16384, 32768, 16384, 32768,
+            16384, 16384, 16384, 16384,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let rows = board.rows();
+
+        // When
+        let inverted_evaluation = evaluator.evaluate_row(rows[0]);
+        let constant_evaluation = evaluator.evaluate_row(rows[1]);
+
+        // Then
+        assert!(inverted_evaluation < 0.);
+        assert_eq!(0., constant_evaluation);
+    }
+
+    #[test]
+    fn test_smoothness_evaluator_with_high_power() {
+        // Given
+        let evaluator = SmoothnessEvaluator {
+            gameover_penalty: 0.,
+            power: 10,
+        };
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 32768, 2, 32768,
+            2, 4, 2, 4,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let rows = board.rows();
+
+        // When
+        let rough_evaluation = evaluator.evaluate_row(rows[0]);
+        let smooth_evaluation = evaluator.evaluate_row(rows[1]);
+
+        // Then
+        assert_eq!(-(u32::MAX as f32), rough_evaluation);
+        assert!(rough_evaluation < smooth_evaluation);
+    }
+
+    #[test]
+    fn test_danger_level() {
+        // Given
+        #[rustfmt::skip]
+        let nearly_empty_board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        #[rustfmt::skip]
+        let one_move_from_loss_board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 8, 8,
+        ]);
+        #[rustfmt::skip]
+        let game_over_board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+
+        // When
+        let empty_danger_level = danger_level(Board::default());
+        let nearly_empty_danger_level = danger_level(nearly_empty_board);
+        let one_move_from_loss_danger_level = danger_level(one_move_from_loss_board);
+        let game_over_danger_level = danger_level(game_over_board);
+
+        // Then
+        assert_eq!(0., empty_danger_level);
+        assert!(nearly_empty_danger_level < 0.1);
+        assert_eq!(1., one_move_from_loss_danger_level);
+        assert_eq!(1., game_over_danger_level);
+    }
 }
@@ -1,36 +1,336 @@
 use crate::board::{Board, Direction};
-use rand::prelude::ThreadRng;
-use rand::Rng;
+use crate::evaluators::BoardEvaluator;
+use crate::solver::cmp_scores;
+use crate::utils::get_exponent;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// Main object containing the state of the game
 pub struct Game {
     pub board: Board,
+    pub score: u32,
     pub proba_4: f32,
-    rng: ThreadRng,
+    rng: StdRng,
+    /// previous states of the game, along with the moves played from them
+    undo_stack: Vec<HistoryEntry>,
+    /// undone states of the game, along with the moves which led to them
+    redo_stack: Vec<HistoryEntry>,
+    continue_after_win: bool,
+    /// value of the tile which must be reached to win the game
+    target_tile: u16,
+    spawn_log: SpawnLog,
+    /// spawns which are replayed before falling back to the random number generator
+    replayed_spawns: VecDeque<(u8, u16)>,
+    spawn_mode: SpawnMode,
 }
 
 impl Game {
-    /// Updates the game state my moving tiles with the provided direction
-    pub fn play(&mut self, direction: Direction) {
-        self.board = self.board.move_to(direction);
+    /// Updates the game state by moving tiles with the provided direction
+    /// The returned `MoveOutcome` indicates whether or not tiles have been moved, in which case a
+    /// new tile is expected to be populated with `populate_new_tile`
+    pub fn play(&mut self, direction: Direction) -> MoveOutcome {
+        let (new_board, score_gained) = self.board.move_to_with_score(direction);
+        if new_board == self.board {
+            return MoveOutcome::default();
+        }
+        // each merge removes exactly one tile from the board
+        let merged_tiles = (new_board.count_empty_tiles() - self.board.count_empty_tiles()) as u8;
+        self.undo_stack.push(HistoryEntry {
+            board: self.board,
+            score: self.score,
+            direction,
+            spawn_idx: self.spawn_log.spawns.len(),
+        });
+        self.redo_stack.clear();
+        self.board = new_board;
+        self.score += score_gained;
+        MoveOutcome {
+            moved: true,
+            score_gained,
+            merged_tiles,
+        }
+    }
+
+    /// Plays the provided move and, only if it was legal, populates a new tile
+    /// Returns `None` when the move was illegal, in which case the game is left unchanged.
+    pub fn step(&mut self, direction: Direction) -> Option<MoveOutcome> {
+        let outcome = self.play(direction);
+        if !outcome.moved {
+            return None;
+        }
+        self.populate_new_tile();
+        Some(outcome)
+    }
+
+    /// Restores the state of the game preceding the last move, including the tile which was
+    /// populated after it
+    /// Returns `false` when there is no move to undo
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(entry) => {
+                self.redo_stack.push(HistoryEntry {
+                    board: self.board,
+                    score: self.score,
+                    ..entry
+                });
+                self.board = entry.board;
+                self.score = entry.score;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restores the state of the game which was undone by the last call to `undo`
+    /// Returns `false` when there is no move to redo
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(entry) => {
+                self.undo_stack.push(HistoryEntry {
+                    board: self.board,
+                    score: self.score,
+                    ..entry
+                });
+                self.board = entry.board;
+                self.score = entry.score;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the number of moves played so far, undone moves being excluded
+    pub fn moves_count(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Returns the status of the game, computed from the current board
+    /// The game is won as soon as the target tile is reached. It is only considered as continued when
+    /// the game has been built with `continue_after_win` and moves are still possible.
+    pub fn status(&self) -> GameStatus {
+        let is_game_over = self.board.is_game_over();
+        if self.board.max_value() >= self.target_tile {
+            GameStatus::Won {
+                continued: self.continue_after_win && !is_game_over,
+            }
+        } else if is_game_over {
+            GameStatus::Lost
+        } else {
+            GameStatus::Playing
+        }
     }
 
     /// Randomly generates a new tile in an empty square
     /// The generated tile value follows a Bernoulli distribution with probability of 4 being
     /// `self.proba_4` and probability of 2 being `1 - self.proba_4`
+    /// When the game has been built from a `SpawnLog`, the logged spawns are replayed instead.
+    /// Nothing is populated when the board has no empty tile.
     pub fn populate_new_tile(&mut self) {
-        let rnd_value: f32 = self.rng.gen();
-        let populated_value = if rnd_value < self.proba_4 { 4 } else { 2 };
-        let empty_tiles: Vec<_> = self.board.empty_tiles_indices().collect();
-        let mut rnd_idx: usize = self.rng.gen();
-        rnd_idx %= empty_tiles.len();
-        self.board = self.board.set_value(empty_tiles[rnd_idx], populated_value);
+        let nb_empty_tiles = self.board.count_empty_tiles();
+        if nb_empty_tiles == 0 {
+            return;
+        }
+        let (tile_idx, populated_value) = match self.replayed_spawns.pop_front() {
+            Some(spawn) => spawn,
+            None => {
+                let rnd_value: f32 = self.rng.gen();
+                let populated_value = if rnd_value < self.proba_4 { 4 } else { 2 };
+                (
+                    self.choose_tile(populated_value, nb_empty_tiles),
+                    populated_value,
+                )
+            }
+        };
+        self.board = self.board.set_value(tile_idx, populated_value);
+        self.spawn_log.spawns.push((tile_idx, populated_value));
+    }
+
+    /// Returns the record of the moves played since the beginning of the game, along with the
+    /// tiles populated after each of them
+    /// The record is built from the played moves and the `SpawnLog`, excluding undone moves.
+    /// `None` is returned when a move has not been followed by the population of a new tile, as
+    /// done by `step`.
+    #[cfg(feature = "serde")]
+    pub fn to_record(&self) -> Option<GameRecord> {
+        let next_boards = self
+            .undo_stack
+            .iter()
+            .skip(1)
+            .map(|entry| entry.board)
+            .chain(Some(self.board));
+        let moves = self
+            .undo_stack
+            .iter()
+            .zip(next_boards)
+            .map(|(entry, next_board)| {
+                let (tile_idx, value) = *self.spawn_log.spawns.get(entry.spawn_idx)?;
+                let moved_board = entry.board.move_to(entry.direction);
+                if moved_board.get_value(tile_idx) != 0
+                    || moved_board.set_value(tile_idx, value) != next_board
+                {
+                    return None;
+                }
+                Some((entry.direction, tile_idx, value))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(GameRecord {
+            initial: self
+                .undo_stack
+                .first()
+                .map_or(self.board, |entry| entry.board),
+            moves,
+            final_score: self.score,
+        })
+    }
+
+    /// Chooses the empty tile in which the new tile is populated, according to the `SpawnMode`
+    fn choose_tile(&mut self, populated_value: u16, nb_empty_tiles: usize) -> u8 {
+        match &self.spawn_mode {
+            SpawnMode::Uniform => {
+                let mut rnd_idx: usize = self.rng.gen();
+                rnd_idx %= nb_empty_tiles;
+                self.board.empty_tiles_indices().nth(rnd_idx).unwrap()
+            }
+            SpawnMode::Adversarial(evaluator) => {
+                let board = self.board;
+                board
+                    .empty_tiles_indices()
+                    .map(|idx| {
+                        (
+                            idx,
+                            evaluator.evaluate(board.set_value(idx, populated_value)),
+                        )
+                    })
+                    // negating the scores keeps NaN evaluations below the other ones, so that they
+                    // are never preferred over finite ones
+                    .min_by(|(_, lhs), (_, rhs)| cmp_scores(-rhs, -lhs))
+                    .map(|(idx, _)| idx)
+                    .unwrap()
+            }
+            SpawnMode::Weighted(weight) => {
+                let total_weight: f32 = self.board.empty_tiles_indices().map(weight).sum();
+                let mut rnd_weight = self.rng.gen::<f32>() * total_weight;
+                let mut empty_tiles = self.board.empty_tiles_indices();
+                let mut tile_idx = empty_tiles.next().unwrap();
+                for idx in empty_tiles {
+                    rnd_weight -= weight(tile_idx);
+                    if rnd_weight < 0. {
+                        break;
+                    }
+                    tile_idx = idx;
+                }
+                tile_idx
+            }
+        }
+    }
+
+    /// Returns the log of the tiles populated since the beginning of the game
+    pub fn spawn_log(&self) -> &SpawnLog {
+        &self.spawn_log
+    }
+}
+
+/// State of the game before or after a move, along with the move itself
+/// The move is only read when exporting a `GameRecord`.
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+struct HistoryEntry {
+    board: Board,
+    score: u32,
+    direction: Direction,
+    /// index in the `SpawnLog` of the tile populated after the move
+    spawn_idx: usize,
+}
+
+/// Sequence of the tiles populated during a game, as `(tile_index, value)` pairs
+/// The log includes the initial tile when the game has been built without an initial board.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct SpawnLog {
+    spawns: Vec<(u8, u16)>,
+}
+
+impl SpawnLog {
+    pub fn spawns(&self) -> &[(u8, u16)] {
+        &self.spawns
+    }
+}
+
+/// Record of a game, which can be exported in order to be replayed
+#[cfg(feature = "serde")]
+#[derive(Clone, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GameRecord {
+    pub initial: Board,
+    /// moves along with the index and the value of the tile populated after each of them
+    pub moves: Vec<(Direction, u8, u16)>,
+    pub final_score: u32,
+}
+
+#[cfg(feature = "serde")]
+impl GameRecord {
+    /// Replays the recorded moves and returns the resulting game
+    pub fn replay(&self) -> Game {
+        let spawn_log = SpawnLog {
+            spawns: self
+                .moves
+                .iter()
+                .map(|(_, tile_idx, value)| (*tile_idx, *value))
+                .collect(),
+        };
+        let mut game = GameBuilder::default()
+            .initial_board(self.initial)
+            .from_spawn_log(spawn_log)
+            .build();
+        for (direction, _, _) in self.moves.iter() {
+            game.step(*direction);
+        }
+        game
     }
 }
 
+/// Strategy used to choose the empty tile in which a new tile is populated
+pub enum SpawnMode {
+    /// the tile is chosen uniformly at random, as in the original game
+    Uniform,
+    /// the tile is chosen so as to minimize the evaluation of the resulting board
+    Adversarial(Box<dyn BoardEvaluator>),
+    /// the tile is chosen at random with a probability proportional to the weight given to its
+    /// index
+    Weighted(fn(u8) -> f32),
+}
+
+/// Status of a `Game`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameStatus {
+    Playing,
+    /// the target tile has been reached, `continued` indicating whether or not the game goes on
+    Won {
+        continued: bool,
+    },
+    Lost,
+}
+
+/// Result of a move played in a `Game`
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MoveOutcome {
+    /// whether or not the move changed the board, i.e. was legal
+    pub moved: bool,
+    /// sum of the values of the tiles resulting from merges
+    pub score_gained: u32,
+    /// number of merges performed by the move
+    pub merged_tiles: u8,
+}
+
 pub struct GameBuilder {
     initial_board: Option<Board>,
     proba_4: f32,
+    seed: Option<u64>,
+    continue_after_win: bool,
+    target_tile: u16,
+    spawn_log: Option<SpawnLog>,
+    spawn_mode: SpawnMode,
 }
 
 impl Default for GameBuilder {
@@ -38,6 +338,11 @@ impl Default for GameBuilder {
         Self {
             initial_board: None,
             proba_4: 0.2,
+            seed: None,
+            continue_after_win: false,
+            target_tile: 2048,
+            spawn_log: None,
+            spawn_mode: SpawnMode::Uniform,
         }
     }
 }
@@ -53,20 +358,556 @@ impl GameBuilder {
         self
     }
 
+    /// Sets the seed of the random number generator used to spawn new tiles, which makes the
+    /// game reproducible. When no seed is provided, the generator is seeded from entropy, or with
+    /// `0` without the `std` feature.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets whether or not the game goes on once the target tile has been reached
+    pub fn continue_after_win(mut self, continue_after_win: bool) -> Self {
+        self.continue_after_win = continue_after_win;
+        self
+    }
+
+    /// Sets the value of the tile which must be reached to win the game, 2048 by default
+    /// This panics when the value is not a power of two.
+    pub fn target_tile(mut self, target_tile: u16) -> Self {
+        assert_ne!(0, get_exponent(target_tile), "Invalid target tile 0");
+        self.target_tile = target_tile;
+        self
+    }
+
+    /// Sets a log of spawns which is replayed, in place of the random number generator, to
+    /// populate the new tiles of the game
+    /// Playing the same moves as in the logged game then reproduces it exactly.
+    pub fn from_spawn_log(mut self, spawn_log: SpawnLog) -> Self {
+        self.spawn_log = Some(spawn_log);
+        self
+    }
+
+    /// Sets the strategy used to choose the empty tile in which new tiles are populated, which
+    /// is `SpawnMode::Uniform` by default
+    pub fn spawn_mode(mut self, spawn_mode: SpawnMode) -> Self {
+        self.spawn_mode = spawn_mode;
+        self
+    }
+
     pub fn build(self) -> Game {
         let proba_4 = self.proba_4;
-        let mut rng = rand::thread_rng();
+        #[cfg(feature = "std")]
+        let mut rng = self
+            .seed
+            .map(StdRng::seed_from_u64)
+            .unwrap_or_else(StdRng::from_entropy);
+        // there is no source of entropy without `std`
+        #[cfg(not(feature = "std"))]
+        let mut rng = StdRng::seed_from_u64(self.seed.unwrap_or(0));
+        let mut replayed_spawns: VecDeque<_> = self
+            .spawn_log
+            .map(|log| log.spawns.into_iter().collect())
+            .unwrap_or_default();
+        let mut spawn_log = SpawnLog::default();
         let board = self.initial_board.unwrap_or_else(|| {
-            let rand_value: f32 = rng.gen();
-            let initial_value = if rand_value < proba_4 { 4 } else { 2 };
-            let rand_idx: u8 = rng.gen();
+            let (tile_idx, initial_value) = replayed_spawns.pop_front().unwrap_or_else(|| {
+                let rand_value: f32 = rng.gen();
+                let initial_value = if rand_value < proba_4 { 4 } else { 2 };
+                let rand_idx: u8 = rng.gen();
+                (rand_idx % 16, initial_value)
+            });
+            spawn_log.spawns.push((tile_idx, initial_value));
             let board = Board::default();
-            board.set_value(rand_idx % 16, initial_value)
+            board.set_value(tile_idx, initial_value)
         });
         Game {
             board,
+            score: 0,
             proba_4: self.proba_4,
             rng,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            continue_after_win: self.continue_after_win,
+            target_tile: self.target_tile,
+            spawn_log,
+            replayed_spawns,
+            spawn_mode: self.spawn_mode,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluators::SnakeEvaluator;
+
+    #[test]
+    fn should_be_reproducible_with_seed() {
+        // Given
+        let moves = [
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Down,
+            Direction::Up,
+            Direction::Left,
+        ];
+        let mut game_1 = GameBuilder::default().seed(42).build();
+        let mut game_2 = GameBuilder::default().seed(42).build();
+
+        // When
+        for direction in moves.iter() {
+            for game in [&mut game_1, &mut game_2].iter_mut() {
+                game.play(*direction);
+                game.populate_new_tile();
+            }
+        }
+
+        // Then
+        assert_eq!(game_1.board, game_2.board);
+    }
+
+    #[test]
+    fn should_replay_spawn_log() {
+        // Given
+        let moves = [
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Down,
+            Direction::Up,
+            Direction::Left,
+        ];
+        let mut game = GameBuilder::default().build();
+        for direction in moves.iter() {
+            game.step(*direction);
+        }
+        let mut replayed_game = GameBuilder::default()
+            .from_spawn_log(game.spawn_log().clone())
+            .build();
+
+        // When
+        for direction in moves.iter() {
+            replayed_game.step(*direction);
         }
+
+        // Then
+        assert_eq!(game.board, replayed_game.board);
+        assert_eq!(game.spawn_log(), replayed_game.spawn_log());
+    }
+
+    #[test]
+    fn should_populate_last_empty_tile() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 0, 4,
+            4, 2, 4, 2,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        game.populate_new_tile();
+
+        // Then
+        assert_eq!(0, game.board.count_empty_tiles());
+        assert_ne!(0, game.board.get_value(10));
+    }
+
+    #[test]
+    fn should_not_populate_full_board() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        game.populate_new_tile();
+
+        // Then
+        assert_eq!(board, game.board);
+        assert!(game.spawn_log().spawns().is_empty());
+    }
+
+    #[test]
+    fn should_populate_adversarial_tile() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            0, 2, 4, 2,
+        ]);
+        let mut game = GameBuilder::default()
+            .initial_board(board)
+            .spawn_mode(SpawnMode::Adversarial(Box::<SnakeEvaluator>::default()))
+            .build();
+
+        // When
+        game.populate_new_tile();
+
+        // Then
+        // the bottom-left corner has the lowest weight in the snake evaluator
+        assert_eq!(0, game.board.get_value(0));
+        assert_ne!(0, game.board.get_value(12));
+    }
+
+    #[test]
+    fn should_populate_weighted_tile() {
+        // Given
+        let mut game = GameBuilder::default()
+            .initial_board(Board::default())
+            .spawn_mode(SpawnMode::Weighted(|idx| if idx == 5 { 1. } else { 0. }))
+            .build();
+
+        // When
+        game.populate_new_tile();
+
+        // Then
+        assert_ne!(0, game.board.get_value(5));
+        assert_eq!(15, game.board.count_empty_tiles());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_replay_game_record() {
+        // Given
+        let moves = [
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+            Direction::Down,
+            Direction::Up,
+            Direction::Left,
+        ];
+        let mut game = GameBuilder::default().seed(42).build();
+        for direction in moves.iter() {
+            game.step(*direction);
+        }
+
+        // When
+        let json = serde_json::to_string(&game.to_record().unwrap()).unwrap();
+        let record: GameRecord = serde_json::from_str(&json).unwrap();
+        let replayed_game = record.replay();
+
+        // Then
+        assert_eq!(game.board, replayed_game.board);
+        assert_eq!(game.score, replayed_game.score);
+        assert_eq!(game.score, record.final_score);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_record_played_direction() {
+        // Given
+        let board = Board::default().set_value(0, 2);
+        let mut game = GameBuilder::default().initial_board(board).seed(5).build();
+
+        // When
+        game.step(Direction::Down);
+        let record = game.to_record().unwrap();
+
+        // Then
+        // moving right then spawning at index 12 would lead to the same board
+        assert_eq!(&[(3, 2)], game.spawn_log().spawns());
+        assert_eq!(vec![(Direction::Down, 3, 2)], record.moves);
+        assert_eq!(game.board, record.replay().board);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_record_game_after_undo() {
+        // Given
+        let mut game = GameBuilder::default().seed(42).build();
+        game.step(Direction::Left);
+        game.step(Direction::Down);
+        game.undo();
+        game.step(Direction::Up);
+        game.step(Direction::Right);
+        game.undo();
+        game.redo();
+
+        // When
+        let record = game.to_record().unwrap();
+
+        // Then
+        let directions: Vec<_> = record.moves.iter().map(|(d, _, _)| *d).collect();
+        assert_eq!(
+            vec![Direction::Left, Direction::Up, Direction::Right],
+            directions
+        );
+        assert_eq!(game.board, record.replay().board);
+    }
+
+    #[test]
+    fn should_play_legal_move() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 4,
+            0, 0, 0, 0,
+            8, 0, 8, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        let outcome = game.play(Direction::Left);
+
+        // Then
+        let expected_outcome = MoveOutcome {
+            moved: true,
+            score_gained: 20,
+            merged_tiles: 2,
+        };
+        assert_eq!(expected_outcome, outcome);
+        assert_eq!(20, game.score);
+    }
+
+    #[test]
+    fn should_play_illegal_move() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 0, 0,
+            0, 0, 0, 0,
+            8, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        let outcome = game.play(Direction::Left);
+
+        // Then
+        assert!(!outcome.moved);
+        assert_eq!(0, outcome.score_gained);
+        assert_eq!(board, game.board);
+    }
+
+    #[test]
+    fn should_step_with_legal_move() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 4,
+            0, 0, 0, 0,
+            8, 0, 8, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        let outcome = game.step(Direction::Left);
+
+        // Then
+        assert_eq!(Some(20), outcome.map(|o| o.score_gained));
+        assert_eq!(12, game.board.count_empty_tiles());
+    }
+
+    #[test]
+    fn should_not_step_with_illegal_move() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 0, 0,
+            0, 0, 0, 0,
+            8, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        let outcome = game.step(Direction::Left);
+
+        // Then
+        assert_eq!(None, outcome);
+        assert_eq!(board, game.board);
+        assert_eq!(13, game.board.count_empty_tiles());
+    }
+
+    #[test]
+    fn should_be_playing() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 0, 0,
+            0, 1024, 0, 0,
+            8, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        let status = game.status();
+
+        // Then
+        assert_eq!(GameStatus::Playing, status);
+    }
+
+    #[test]
+    fn should_be_won() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 0, 0,
+            0, 2048, 0, 0,
+            8, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let game = GameBuilder::default().initial_board(board).build();
+        let continued_game = GameBuilder::default()
+            .initial_board(board)
+            .continue_after_win(true)
+            .build();
+
+        // When
+        let status = game.status();
+        let continued_status = continued_game.status();
+
+        // Then
+        assert_eq!(GameStatus::Won { continued: false }, status);
+        assert_eq!(GameStatus::Won { continued: true }, continued_status);
+    }
+
+    #[test]
+    fn should_be_won_with_target_tile() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 0, 0,
+            0, 2048, 0, 0,
+            8, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let game = GameBuilder::default()
+            .initial_board(board)
+            .target_tile(4096)
+            .build();
+        let won_game = GameBuilder::default()
+            .initial_board(board.set_value(5, 4096))
+            .target_tile(4096)
+            .build();
+
+        // When
+        let status = game.status();
+        let won_status = won_game.status();
+
+        // Then
+        assert_eq!(GameStatus::Playing, status);
+        assert_eq!(GameStatus::Won { continued: false }, won_status);
+    }
+
+    #[test]
+    fn should_be_lost() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+        let game = GameBuilder::default().initial_board(board).build();
+
+        // When
+        let status = game.status();
+
+        // Then
+        assert_eq!(GameStatus::Lost, status);
+    }
+
+    #[test]
+    fn should_undo_move() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).seed(7).build();
+        game.play(Direction::Right);
+        game.populate_new_tile();
+
+        // When
+        let undone = game.undo();
+
+        // Then
+        assert!(undone);
+        assert_eq!(board, game.board);
+        assert_eq!(0, game.score);
+    }
+
+    #[test]
+    fn should_count_moves_excluding_undone_ones() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).seed(7).build();
+
+        // When
+        game.play(Direction::Right);
+        game.play(Direction::Left);
+        game.play(Direction::Left);
+        game.undo();
+
+        // Then
+        assert_eq!(1, game.moves_count());
+    }
+
+    #[test]
+    fn should_redo_undone_move() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).seed(7).build();
+        game.play(Direction::Left);
+        game.populate_new_tile();
+        let played_board = game.board;
+        game.undo();
+
+        // When
+        let redone = game.redo();
+
+        // Then
+        assert!(redone);
+        assert_eq!(played_board, game.board);
+        assert_eq!(4, game.score);
+        assert!(!game.redo());
+    }
+
+    #[test]
+    fn should_not_undo_at_start() {
+        // Given
+        let mut game = GameBuilder::default().build();
+        let initial_board = game.board;
+
+        // When
+        let undone = game.undo();
+
+        // Then
+        assert!(!undone);
+        assert_eq!(initial_board, game.board);
     }
 }
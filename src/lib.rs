@@ -1,5 +1,17 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 pub mod board;
+pub mod error;
 pub mod evaluators;
 pub mod game;
+#[cfg(feature = "std")]
+pub mod self_play;
+#[cfg(feature = "std")]
+pub mod sized_board;
 pub mod solver;
+pub mod tile_scheme;
+#[cfg(feature = "std")]
+pub mod tuning;
 mod utils;
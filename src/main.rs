@@ -1,28 +1,36 @@
-use crate::board::{Board, Direction};
-use crate::evaluators::*;
-use crate::game::{Game, GameBuilder};
-use crate::solver::{Solver, SolverBuilder};
-use clap::{App, AppSettings, Arg, ArgMatches};
-use std::io::{stdout, StdoutLock, Write};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use play_2048::board::{Board, Direction};
+use play_2048::evaluators::*;
+use play_2048::game::{Game, GameBuilder};
+use play_2048::solver::{GameResult, Solver, SolverBuilder};
+use std::fs::read_to_string;
+use std::io::{stdin, stdout, Read, StdoutLock, Write};
+use std::process::exit;
 use std::str::FromStr;
-use std::thread::sleep;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
+use std::thread::{sleep, spawn};
 use std::time::{Duration, Instant};
 use termion::event::Key;
 use termion::input::TermRead;
 use termion::raw::IntoRawMode;
-use termion::{async_stdin, clear, cursor, style};
+use termion::{clear, color, cursor, style};
 
-mod board;
-mod evaluators;
-mod game;
-mod solver;
-mod utils;
+/// Seed of the random number generator in scripted mode, so that scripted games are reproducible
+const SCRIPT_SEED: u64 = 0;
+
+/// Danger level above which a warning is displayed in the header
+const DANGER_WARNING_LEVEL: f32 = 0.8;
 
 mod graphics {
     pub const CONTROLS: &str = "╓─────────┬─────CONTROLS─────────╖\n\r\
                                 ║ ← ↑ → ↓ | move tiles           ║\n\r\
                                 ║      p  | use AI for next move ║\n\r\
                                 ║      a  | toggle AI autoplay   ║\n\r\
+                                ║      n  | pause and step AI    ║\n\r\
+                                ║      h  | show AI hint         ║\n\r\
+                                ║      r  | restart              ║\n\r\
                                 ║      q  | quit                 ║\n\r\
                                 ╚═════════╧══════════════════════╝";
 }
@@ -35,6 +43,7 @@ fn get_app<'a, 'b>() -> App<'a, 'b> {
         .arg(
             Arg::with_name("proba_4")
                 .short("p")
+                .global(true)
                 .long("--proba-4")
                 .takes_value(true)
                 .default_value("0.1")
@@ -43,6 +52,7 @@ fn get_app<'a, 'b>() -> App<'a, 'b> {
         .arg(
             Arg::with_name("depth")
                 .short("d")
+                .global(true)
                 .long("--depth")
                 .takes_value(true)
                 .default_value("3")
@@ -55,6 +65,7 @@ fn get_app<'a, 'b>() -> App<'a, 'b> {
         .arg(
             Arg::with_name("min_branch_proba")
                 .short("m")
+                .global(true)
                 .long("--min-branch-proba")
                 .takes_value(true)
                 .default_value("0.001")
@@ -64,6 +75,72 @@ fn get_app<'a, 'b>() -> App<'a, 'b> {
                     algorithm.",
                 ),
         )
+        .arg(
+            Arg::with_name("delay_ms")
+                .long("--delay-ms")
+                .takes_value(true)
+                .default_value("0")
+                .validator(|delay_ms| match u64::from_str(&delay_ms) {
+                    Ok(_) => Ok(()),
+                    Err(_) => Err(String::from("the delay must be a number of milliseconds")),
+                })
+                .help("Minimum delay, in milliseconds, between two AI moves during autoplay"),
+        )
+        .arg(
+            Arg::with_name("seed")
+                .short("s")
+                .long("--seed")
+                .takes_value(true)
+                .validator(|seed| match u64::from_str(&seed) {
+                    Ok(_) => Ok(()),
+                    Err(_) => Err(String::from("the seed must be a non-negative integer")),
+                })
+                .help(
+                    "Seed of the random number generator, which can be used to reproduce a game. \
+                    A random seed is generated when omitted, except in scripted mode.",
+                ),
+        )
+        .arg(
+            Arg::with_name("board")
+                .short("b")
+                .long("--board")
+                .takes_value(true)
+                .value_name("FILE")
+                .help(
+                    "Starts the game from the board stored in FILE, either as 16 tile values \
+                    separated by whitespaces or in the compact format, e.g. \
+                    '2 . . . / . 4 . . / . . . . / . . . 2048'.",
+                ),
+        )
+        .arg(
+            Arg::with_name("script")
+                .long("--script")
+                .takes_value(true)
+                .min_values(0)
+                .value_name("FILE")
+                .help(
+                    "Plays the moves listed in FILE, or read from stdin when no file is \
+                    provided, and prints the final board and score. Moves are separated by \
+                    whitespaces and are either a direction (l, r, u, d), 'p' to use the AI for \
+                    the next move, or 'a' to let the AI play until the game is over.",
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Plays several games with the AI and prints statistics about them")
+                .arg(
+                    Arg::with_name("games")
+                        .short("n")
+                        .long("--games")
+                        .takes_value(true)
+                        .default_value("10")
+                        .validator(|games| match u64::from_str(&games) {
+                            Ok(games) if games > 0 => Ok(()),
+                            _ => Err(String::from("the number of games must be positive")),
+                        })
+                        .help("Number of games to play, seeded from 0 to n - 1"),
+                ),
+        )
 }
 
 fn get_solver(matches: &ArgMatches) -> Solver {
@@ -99,40 +176,141 @@ fn get_solver(matches: &ArgMatches) -> Solver {
         .build()
 }
 
-fn update_board(board: Board, stdout: &mut StdoutLock) {
+fn render_header(game: &Game) -> String {
+    let header = format!("Score: {}    Moves: {}", game.score, game.moves_count());
+    let danger = danger_level(game.board);
+    if danger < DANGER_WARNING_LEVEL {
+        return header;
+    }
+    format!(
+        "{}    {}Danger: {:.0}%{}",
+        header,
+        color::Fg(color::Red),
+        danger * 100.,
+        color::Fg(color::Reset)
+    )
+}
+
+/// Returns the controls legend with the arrow of the move recommended by the solver in bold,
+/// without playing it
+fn format_hint(board: Board, solver: &mut Solver) -> String {
+    match solver.next_best_move(board) {
+        Some(direction) => {
+            let arrow = match direction {
+                Direction::Left => "←",
+                Direction::Right => "→",
+                Direction::Up => "↑",
+                Direction::Down => "↓",
+            };
+            let highlighted_arrow = format!("{}{}{}", style::Bold, arrow, style::Reset);
+            graphics::CONTROLS.replacen(arrow, &highlighted_arrow, 1)
+        }
+        None => String::from(graphics::CONTROLS),
+    }
+}
+
+fn update_board(game: &Game, stdout: &mut StdoutLock) {
+    update_board_with_controls(game, graphics::CONTROLS, stdout)
+}
+
+fn update_board_with_controls(game: &Game, controls: &str, stdout: &mut StdoutLock) {
     write!(
         stdout,
-        "{}{}\n{}{}",
+        "{}{}{}{}{}{}{}\n{}{}",
+        cursor::Goto(1, 3),
+        clear::CurrentLine,
+        render_header(game),
+        cursor::Goto(1, 4),
+        clear::CurrentLine,
         cursor::Goto(1, 5),
-        board,
-        graphics::CONTROLS,
+        game.board,
+        controls,
         cursor::Hide
     )
     .unwrap();
 }
 
+fn format_game_over_banner(game: &Game) -> String {
+    format!(
+        "GAME OVER — final score {} — press q to quit or r to restart",
+        game.score
+    )
+}
+
 fn play(game: &mut Game, direction: Direction, stdout: &mut StdoutLock) {
-    let previous_board = game.board;
-    game.play(direction);
-    if previous_board == game.board {
-        return;
+    if game.step(direction).is_some() {
+        update_board(game, stdout);
+        if game.board.is_game_over() {
+            write!(
+                stdout,
+                "{}{}{}{}",
+                cursor::Goto(1, 4),
+                style::Bold,
+                format_game_over_banner(game),
+                style::Reset
+            )
+            .unwrap();
+        }
     }
-    update_board(game.board, stdout);
-    game.populate_new_tile();
-    update_board(game.board, stdout);
 }
 
-fn main() {
-    let matches = get_app().get_matches();
-    let mut solver = get_solver(&matches);
-    let proba_4 = f32::from_str(matches.value_of("proba_4").unwrap()).unwrap();
+/// Reads the keys from stdin in a background thread, setting `cancel` at each key press so that a
+/// running search returns early and the key is handled without delay
+fn spawn_keys_reader(cancel: Arc<AtomicBool>) -> Receiver<Key> {
+    let (sender, receiver) = channel();
+    spawn(move || {
+        for key in stdin().keys().flatten() {
+            cancel.store(true, Ordering::Relaxed);
+            if sender.send(key).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
 
-    let stdout = stdout();
-    let mut stdout = stdout.lock().into_raw_mode().unwrap();
-    let mut stdin = async_stdin().keys();
+/// Returns the next move of the AI, the search being canceled as soon as a key is pressed
+fn next_ai_move(solver: &mut Solver, board: Board, cancel: &AtomicBool) -> Option<Direction> {
+    cancel.store(false, Ordering::Relaxed);
+    solver.next_best_move_cancelable(board, cancel)
+}
 
-    write!(stdout, "{}{}", clear::All, cursor::Goto(1, 1)).unwrap();
+/// Builds a new game and renders it, along with its seed
+fn start_game(proba_4: f32, seed: u64, board: Option<Board>, stdout: &mut StdoutLock) -> Game {
+    write!(stdout, "{}{}Seed: {}", clear::All, cursor::Goto(1, 1), seed).unwrap();
+    let game = build_game(proba_4, seed, board);
+    update_board(&game, stdout);
+    game
+}
 
+/// Paces the AI moves during autoplay
+struct AutoplayTimer {
+    delay: Duration,
+    last_move: Option<Instant>,
+}
+
+impl AutoplayTimer {
+    fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            last_move: None,
+        }
+    }
+
+    /// Returns `true`, and restarts the timer, when the delay since the last AI move is elapsed
+    fn is_ready(&mut self) -> bool {
+        let now = Instant::now();
+        match self.last_move {
+            Some(last_move) if now.duration_since(last_move) < self.delay => false,
+            _ => {
+                self.last_move = Some(now);
+                true
+            }
+        }
+    }
+}
+
+fn get_initial_board() -> Board {
     #[rustfmt::skip]
     let board: Board = Board::from(vec![
         0, 2, 0, 0,
@@ -140,16 +318,153 @@ fn main() {
         0, 0, 0, 0,
         0, 0, 0, 0,
     ]);
+    board
+}
 
+/// Loads the board stored in the file passed with `--board`, if any
+fn load_board(matches: &ArgMatches) -> Result<Option<Board>, String> {
+    let path = match matches.value_of("board") {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+    let content = read_to_string(path).map_err(|e| format!("Cannot read board file: {}", e))?;
+    let board = if content.contains('/') {
+        Board::from_compact_str(content.trim())
+    } else {
+        Board::from_str(&content)
+    };
+    board
+        .map(Some)
+        .map_err(|e| format!("Invalid board file '{}': {}", path, e))
+}
+
+/// Builds a game starting from `board`, or from the default initial board, on which a new tile
+/// is populated, when no board is provided
+fn build_game(proba_4: f32, seed: u64, board: Option<Board>) -> Game {
     let mut game = GameBuilder::default()
-        .initial_board(board)
+        .initial_board(board.unwrap_or_else(get_initial_board))
         .proba_4(proba_4)
+        .seed(seed)
         .build();
+    if board.is_none() {
+        game.populate_new_tile();
+    }
+    game
+}
+
+fn run_script(script: Option<&str>, game: &mut Game, solver: &mut Solver) {
+    let script = match script {
+        Some(path) => read_to_string(path),
+        None => {
+            let mut script = String::new();
+            stdin().read_to_string(&mut script).map(|_| script)
+        }
+    }
+    .unwrap_or_else(|e| {
+        eprintln!("Cannot read script: {}", e);
+        exit(1)
+    });
+
+    for token in script.split_whitespace() {
+        match token {
+            "p" => {
+                if let Some(next_move) = solver.next_best_move(game.board) {
+                    game.step(next_move);
+                }
+            }
+            "a" => {
+                while let Some(next_move) = solver.next_best_move(game.board) {
+                    game.step(next_move);
+                }
+            }
+            _ => match Direction::from_str(token) {
+                Ok(direction) => {
+                    game.step(direction);
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    exit(1)
+                }
+            },
+        }
+    }
+    println!("{:?}", game.board);
+    println!("Score: {}", game.score);
+}
+
+fn run_bench(matches: &ArgMatches) {
+    let mut solver = get_solver(matches);
+    let proba_4 = f32::from_str(matches.value_of("proba_4").unwrap()).unwrap();
+    let nb_games = u64::from_str(matches.value_of("games").unwrap()).unwrap();
 
-    update_board(game.board, &mut stdout);
-    game.populate_new_tile();
-    update_board(game.board, &mut stdout);
+    let start = Instant::now();
+    let results: Vec<GameResult> = (0..nb_games)
+        .map(|seed| solver.play_out(get_initial_board(), proba_4, seed))
+        .collect();
+    let elapsed = start.elapsed().as_secs_f32();
+
+    let mut scores: Vec<u32> = results.iter().map(|result| result.score).collect();
+    scores.sort_unstable();
+    let median_score = match scores.len() {
+        0 => 0.,
+        len if len % 2 == 0 => (scores[len / 2 - 1] + scores[len / 2]) as f32 / 2.,
+        len => scores[len / 2] as f32,
+    };
+    let reach_rate = |tile: u16| {
+        results
+            .iter()
+            .filter(|result| result.max_tile >= tile)
+            .count() as f32
+            / nb_games as f32
+    };
+    let nb_moves: usize = results.iter().map(|result| result.moves).sum();
+
+    println!("Games: {}", nb_games);
+    println!(
+        "Mean score: {:.1}",
+        scores.iter().sum::<u32>() as f32 / nb_games as f32
+    );
+    println!("Median score: {:.1}", median_score);
+    println!("Max score: {}", scores.last().copied().unwrap_or(0));
+    println!("2048 reach rate: {:.3}", reach_rate(2048));
+    println!("4096 reach rate: {:.3}", reach_rate(4096));
+    println!("Moves per second: {:.1}", nb_moves as f32 / elapsed);
+}
+
+fn main() {
+    let matches = get_app().get_matches();
+    if let Some(bench_matches) = matches.subcommand_matches("bench") {
+        run_bench(bench_matches);
+        return;
+    }
+    let mut solver = get_solver(&matches);
+    let proba_4 = f32::from_str(matches.value_of("proba_4").unwrap()).unwrap();
+    let seed = matches
+        .value_of("seed")
+        .map(|seed| u64::from_str(seed).unwrap());
+    let board = load_board(&matches).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        exit(1)
+    });
+
+    if matches.is_present("script") {
+        let seed = seed.unwrap_or(SCRIPT_SEED);
+        println!("Seed: {}", seed);
+        let mut game = build_game(proba_4, seed, board);
+        run_script(matches.value_of("script"), &mut game, &mut solver);
+        return;
+    }
+
+    let stdout = stdout();
+    let mut stdout = stdout.lock().into_raw_mode().unwrap();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let keys = spawn_keys_reader(Arc::clone(&cancel));
+
+    let mut seed = seed.unwrap_or_else(rand::random);
+    let mut game = start_game(proba_4, seed, board, &mut stdout);
     let mut autoplay = false;
+    let delay_ms = u64::from_str(matches.value_of("delay_ms").unwrap()).unwrap();
+    let mut autoplay_timer = AutoplayTimer::new(Duration::from_millis(delay_ms));
 
     let mut before = Instant::now();
     loop {
@@ -163,25 +478,40 @@ fn main() {
         }
         before = now;
 
-        let input = stdin.next();
-        if let Some(Ok(key)) = input {
+        if let Ok(key) = keys.try_recv() {
             match key {
                 Key::Char('q') => break,
                 Key::Ctrl('c') => break,
+                Key::Char('r') => {
+                    autoplay = false;
+                    seed = rand::random();
+                    game = start_game(proba_4, seed, board, &mut stdout);
+                }
+                _ if game.board.is_game_over() => continue,
                 Key::Left => play(&mut game, Direction::Left, &mut stdout),
                 Key::Right => play(&mut game, Direction::Right, &mut stdout),
                 Key::Up => play(&mut game, Direction::Up, &mut stdout),
                 Key::Down => play(&mut game, Direction::Down, &mut stdout),
                 Key::Char('p') => {
-                    if let Some(next_move) = solver.next_best_move(game.board) {
+                    if let Some(next_move) = next_ai_move(&mut solver, game.board, &cancel) {
                         play(&mut game, next_move, &mut stdout)
                     }
                 }
                 Key::Char('a') => autoplay = !autoplay,
+                Key::Char('h') => {
+                    let controls = format_hint(game.board, &mut solver);
+                    update_board_with_controls(&game, &controls, &mut stdout)
+                }
+                Key::Char('n') => {
+                    autoplay = false;
+                    if let Some(next_move) = next_ai_move(&mut solver, game.board, &cancel) {
+                        play(&mut game, next_move, &mut stdout)
+                    }
+                }
                 _ => continue,
             };
-        } else if autoplay {
-            if let Some(next_move) = solver.next_best_move(game.board) {
+        } else if autoplay && autoplay_timer.is_ready() {
+            if let Some(next_move) = next_ai_move(&mut solver, game.board, &cancel) {
                 play(&mut game, next_move, &mut stdout)
             }
         }
@@ -189,11 +519,143 @@ fn main() {
 
     write!(
         stdout,
-        "{}{}{}{}",
+        "{}{}{}{}Seed: {}\n\r",
         clear::All,
         style::Reset,
         cursor::Goto(1, 1),
         cursor::Show,
+        seed,
     )
     .unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_header() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 0,
+            4, 4, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).seed(0).build();
+        game.step(Direction::Left);
+
+        // When
+        let header = render_header(&game);
+
+        // Then
+        assert_eq!("Score: 12    Moves: 1", header);
+    }
+
+    #[test]
+    fn test_render_header_with_danger_warning() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 8, 8,
+        ]);
+        let game = GameBuilder::default().initial_board(board).seed(0).build();
+
+        // When
+        let header = render_header(&game);
+
+        // Then
+        assert_eq!(
+            format!(
+                "Score: 0    Moves: 0    {}Danger: 100%{}",
+                color::Fg(color::Red),
+                color::Fg(color::Reset)
+            ),
+            header
+        );
+    }
+
+    #[test]
+    fn test_format_game_over_banner() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+        let mut game = GameBuilder::default().initial_board(board).build();
+        game.score = 1024;
+
+        // When
+        let banner = format_game_over_banner(&game);
+
+        // Then
+        assert!(game.board.is_game_over());
+        assert_eq!(
+            "GAME OVER — final score 1024 — press q to quit or r to restart",
+            banner
+        );
+    }
+
+    #[test]
+    fn test_invalid_seed_and_delay_are_usage_errors() {
+        // When
+        let seed_result = get_app().get_matches_from_safe(vec!["2048", "--seed", "-1"]);
+        let delay_result = get_app().get_matches_from_safe(vec!["2048", "--delay-ms", "1.5"]);
+
+        // Then
+        assert_eq!(
+            clap::ErrorKind::ValueValidation,
+            seed_result.unwrap_err().kind
+        );
+        assert_eq!(
+            clap::ErrorKind::ValueValidation,
+            delay_result.unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn test_format_hint() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 8, 16,
+            4, 8, 16, 32,
+            8, 16, 32, 64,
+            2, 0, 0, 0,
+        ]);
+        let mut solver = get_solver(&get_app().get_matches_from(vec!["2048"]));
+
+        // When
+        let hint = format_hint(board, &mut solver);
+
+        // Then
+        let highlighted_arrow = format!("{}↓{}", style::Bold, style::Reset);
+        let expected_hint = graphics::CONTROLS.replace('↓', &highlighted_arrow);
+        assert_eq!(expected_hint, hint);
+        assert!(hint.contains(&format!("║ ← ↑ → {} | move tiles", highlighted_arrow)));
+    }
+
+    #[test]
+    fn test_autoplay_timer_respects_delay() {
+        // Given
+        let mut timer = AutoplayTimer::new(Duration::from_millis(50));
+
+        // When
+        let first_move_ready = timer.is_ready();
+        let second_move_ready_immediately = timer.is_ready();
+        sleep(Duration::from_millis(60));
+        let second_move_ready_after_delay = timer.is_ready();
+
+        // Then
+        assert!(first_move_ready);
+        assert!(!second_move_ready_immediately);
+        assert!(second_move_ready_after_delay);
+    }
+}
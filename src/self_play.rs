@@ -0,0 +1,185 @@
+use crate::board::{Board, Direction};
+use crate::game::{Game, GameBuilder};
+use crate::solver::{Solver, SolverBuilder};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Iterator over `(board, chosen move, expected evaluation)` samples obtained by letting a
+/// `Solver` play games until no move is possible, a new game being started after each one
+///
+/// The samples can be used as training data for other policies. With a positive temperature,
+/// the moves are sampled with probabilities `exp((score - best_score) / temperature)` instead of
+/// always playing the best one, which diversifies the visited boards.
+pub struct SelfPlayGenerator {
+    solver: Solver,
+    proba_4: f32,
+    temperature: f32,
+    rng: StdRng,
+    game: Game,
+}
+
+impl SelfPlayGenerator {
+    /// Returns the move to play along with its expected evaluation
+    fn choose_move(&mut self, board: Board) -> Option<(Direction, f32)> {
+        if self.temperature <= 0. {
+            return self.solver.next_best_move_scored(board);
+        }
+        let ranked_moves = self.solver.rank_moves(board);
+        let best_score = ranked_moves.first()?.1;
+        let weights: Vec<f32> = ranked_moves
+            .iter()
+            .map(|(_, score)| ((score - best_score) / self.temperature).exp())
+            .collect();
+        let mut rnd_weight = self.rng.gen::<f32>() * weights.iter().sum::<f32>();
+        for (scored_move, weight) in ranked_moves.iter().zip(weights) {
+            if rnd_weight < weight {
+                return Some(*scored_move);
+            }
+            rnd_weight -= weight;
+        }
+        ranked_moves.last().copied()
+    }
+}
+
+impl Iterator for SelfPlayGenerator {
+    type Item = (Board, Direction, f32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let board = self.game.board;
+        let (direction, value) = match self.choose_move(board) {
+            Some(scored_move) => scored_move,
+            None => {
+                self.game = new_game(self.proba_4, self.rng.gen());
+                return self.next();
+            }
+        };
+        self.game.step(direction);
+        Some((board, direction, value))
+    }
+}
+
+pub struct SelfPlayGeneratorBuilder {
+    solver: Option<Solver>,
+    proba_4: f32,
+    temperature: f32,
+    seed: u64,
+}
+
+impl Default for SelfPlayGeneratorBuilder {
+    fn default() -> Self {
+        Self {
+            solver: None,
+            proba_4: 0.2,
+            temperature: 0.,
+            seed: 0,
+        }
+    }
+}
+
+impl SelfPlayGeneratorBuilder {
+    /// Sets the solver choosing the moves, a default `Solver` being used otherwise
+    pub fn solver(mut self, solver: Solver) -> Self {
+        self.solver = Some(solver);
+        self
+    }
+
+    pub fn proba_4(mut self, proba: f32) -> Self {
+        self.proba_4 = proba;
+        self
+    }
+
+    /// Sets the temperature with which moves are sampled, `0` meaning that the best move is
+    /// always played
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    /// Sets the seed from which the games and the sampled moves are generated
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn build(self) -> SelfPlayGenerator {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        SelfPlayGenerator {
+            solver: self
+                .solver
+                .unwrap_or_else(|| SolverBuilder::default().build()),
+            proba_4: self.proba_4,
+            temperature: self.temperature,
+            game: new_game(self.proba_4, rng.gen()),
+            rng,
+        }
+    }
+}
+
+fn new_game(proba_4: f32, seed: u64) -> Game {
+    GameBuilder::default()
+        .proba_4(proba_4)
+        .seed(seed)
+        .continue_after_win(true)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_solver() -> Solver {
+        SolverBuilder::default()
+            .base_max_search_depth(1)
+            .min_branch_proba(0.1)
+            .build()
+    }
+
+    #[test]
+    fn test_generate_samples() {
+        // Given
+        let generator = SelfPlayGeneratorBuilder::default()
+            .solver(build_solver())
+            .seed(3)
+            .build();
+        let mut reference_solver = build_solver();
+
+        // When
+        let samples: Vec<_> = generator.take(300).collect();
+
+        // Then
+        assert_eq!(300, samples.len());
+        // a new game has been started once the first one was over
+        assert!(samples[1..]
+            .iter()
+            .any(|(board, _, _)| board.tile_sum() <= 4));
+        for (board, direction, value) in samples {
+            assert_eq!(
+                Some((direction, value)),
+                reference_solver.next_best_move_scored(board)
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_samples_with_temperature() {
+        // Given
+        let build_generator = |temperature| {
+            SelfPlayGeneratorBuilder::default()
+                .solver(build_solver())
+                .temperature(temperature)
+                .seed(3)
+                .build()
+        };
+
+        // When
+        let greedy_samples: Vec<_> = build_generator(0.).take(50).collect();
+        let sampled_samples: Vec<_> = build_generator(1000.).take(50).collect();
+
+        // Then
+        assert_eq!(50, sampled_samples.len());
+        assert_ne!(greedy_samples, sampled_samples);
+        for (board, direction, _) in sampled_samples {
+            assert_ne!(board, board.move_to(direction));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use crate::board::{colorize_tile, get_spaces_prefix, Direction};
+use crate::utils::{build_sized_left_moves_table, build_sized_right_moves_table, get_exponent};
+use lazy_static::lazy_static;
+use std::fmt::{Debug, Display, Formatter};
+
+/// `SizedBoard` represents the state of a `N`x`N` board, for the variants of the game which are
+/// not played on 4x4 boards.
+///
+/// It uses the same representation as `Board`, with 4 bits per tile, but relies on a u128 value
+/// so that boards up to 5x5 can be represented. The supported sizes are 3 and 5, the 4x4 board
+/// being `Board`, and only boards of these sizes implement `SupportedSize` and can be moved.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SizedBoard<const N: usize> {
+    state: u128,
+}
+
+pub type Board3 = SizedBoard<3>;
+pub type Board5 = SizedBoard<5>;
+
+lazy_static! {
+    static ref LEFT_MOVES_TABLE_3: Vec<u32> = build_sized_left_moves_table(3);
+    static ref RIGHT_MOVES_TABLE_3: Vec<u32> = build_sized_right_moves_table(3);
+    static ref LEFT_MOVES_TABLE_5: Vec<u32> = build_sized_left_moves_table(5);
+    static ref RIGHT_MOVES_TABLE_5: Vec<u32> = build_sized_right_moves_table(5);
+}
+
+/// Sizes of `SizedBoard` for which moves tables are available, i.e. 3 and 5
+/// This trait is sealed, so that it cannot be implemented for other sizes.
+pub trait SupportedSize: sealed::MovesTables {}
+
+impl SupportedSize for Board3 {}
+impl SupportedSize for Board5 {}
+
+mod sealed {
+    use super::*;
+
+    pub trait MovesTables {
+        /// Returns the left and right moves tables of the rows of the board
+        fn moves_tables() -> (&'static [u32], &'static [u32]);
+    }
+
+    impl MovesTables for Board3 {
+        fn moves_tables() -> (&'static [u32], &'static [u32]) {
+            (&LEFT_MOVES_TABLE_3, &RIGHT_MOVES_TABLE_3)
+        }
+    }
+
+    impl MovesTables for Board5 {
+        fn moves_tables() -> (&'static [u32], &'static [u32]) {
+            (&LEFT_MOVES_TABLE_5, &RIGHT_MOVES_TABLE_5)
+        }
+    }
+}
+
+impl<const N: usize> SizedBoard<N> {
+    /// Returns the value at the corresponding index
+    pub fn get_value(self, tile_idx: usize) -> u16 {
+        match self.get_exponent_value(tile_idx) {
+            0 => 0,
+            exponent => 1 << exponent as u16,
+        }
+    }
+
+    fn get_exponent_value(self, tile_idx: usize) -> u8 {
+        ((self.state >> Self::tile_shift(tile_idx)) & 0xF) as u8
+    }
+
+    /// Set the tile value at the corresponding index
+    pub fn set_value(self, tile_idx: usize, tile_value: u16) -> Self {
+        let shift = Self::tile_shift(tile_idx);
+        let state = (self.state & !(0xF << shift)) | ((get_exponent(tile_value) as u128) << shift);
+        Self { state }
+    }
+
+    /// Returns the rows, each row being encoded with 4 bits per tile
+    pub fn rows(self) -> [u32; N] {
+        let mut rows = [0; N];
+        for (row_idx, row) in rows.iter_mut().enumerate() {
+            *row = ((self.state >> (4 * N * (N - 1 - row_idx))) & Self::row_mask()) as u32;
+        }
+        rows
+    }
+
+    /// Returns the columns, each column being encoded with 4 bits per tile
+    pub fn columns(self) -> [u32; N] {
+        self.transpose().rows()
+    }
+
+    fn move_rows(self, moves_table: &[u32]) -> Self {
+        let state = self.rows().iter().fold(0, |acc, row| {
+            (acc << (4 * N)) | moves_table[*row as usize] as u128
+        });
+        Self { state }
+    }
+
+    fn transpose(self) -> Self {
+        let mut transposed = Self::default();
+        for row_idx in 0..N {
+            for col_idx in 0..N {
+                let exponent = self.get_exponent_value(row_idx * N + col_idx) as u128;
+                transposed.state |= exponent << Self::tile_shift(col_idx * N + row_idx);
+            }
+        }
+        transposed
+    }
+
+    fn tile_shift(tile_idx: usize) -> usize {
+        4 * (N * N - 1 - tile_idx)
+    }
+
+    fn row_mask() -> u128 {
+        (1 << (4 * N)) - 1
+    }
+
+    fn display(self, f: &mut Formatter<'_>, debug: bool) -> Result<(), std::fmt::Error> {
+        let mut display = String::new();
+        let line_break = if debug { "\n" } else { "\n\r" };
+        let border = |left: &str, middle: &str, right: &str| {
+            format!(
+                "{}{}{}{}",
+                left,
+                vec!["═══════"; N].join(middle),
+                right,
+                line_break
+            )
+        };
+        display.push_str(line_break);
+        display.push_str(&border("╔", "╦", "╗"));
+        for i in 0..N * N {
+            let tile = self.get_value(i);
+            if tile == 0 {
+                display.push_str("║       ");
+            } else if debug {
+                display.push_str(&format!(
+                    "║{prefix}{tile} ",
+                    prefix = get_spaces_prefix(tile),
+                    tile = tile,
+                ));
+            } else {
+                display.push_str(&format!(
+                    "║{prefix}{tile} ",
+                    prefix = get_spaces_prefix(tile),
+                    tile = colorize_tile(tile),
+                ));
+            }
+            if i % N == N - 1 {
+                display.push_str(&format!("║{b}", b = line_break));
+                if i == N * N - 1 {
+                    display.push_str(&border("╚", "╩", "╝"));
+                } else {
+                    display.push_str(&border("╠", "╬", "╣"));
+                }
+            }
+        }
+        write!(f, "{}", display)
+    }
+}
+
+impl<const N: usize> SizedBoard<N>
+where
+    Self: SupportedSize,
+{
+    /// Moves the tiles in the provided `Direction` and returns the resulting `SizedBoard`
+    pub fn move_to(self, direction: Direction) -> Self {
+        let (left_moves_table, right_moves_table) = <Self as sealed::MovesTables>::moves_tables();
+        match direction {
+            Direction::Left => self.move_rows(left_moves_table),
+            Direction::Right => self.move_rows(right_moves_table),
+            Direction::Up => self.transpose().move_rows(left_moves_table).transpose(),
+            Direction::Down => self.transpose().move_rows(right_moves_table).transpose(),
+        }
+    }
+}
+
+/// Converts the `N * N` tile values into a `SizedBoard`
+///
+/// This conversion panics when the number of values does not match the size of the board, or
+/// when a value is not a valid tile value.
+impl<const N: usize> From<Vec<u16>> for SizedBoard<N> {
+    fn from(tiles: Vec<u16>) -> Self {
+        assert_eq!(N * N, tiles.len(), "Invalid number of tiles");
+        let mut state: u128 = 0;
+        for tile_value in tiles.into_iter() {
+            state <<= 4;
+            state |= get_exponent(tile_value) as u128;
+        }
+        Self { state }
+    }
+}
+
+impl<const N: usize> From<SizedBoard<N>> for Vec<u16> {
+    fn from(board: SizedBoard<N>) -> Self {
+        (0..N * N).map(|idx| board.get_value(idx)).collect()
+    }
+}
+
+impl<const N: usize> Display for SizedBoard<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.display(f, false)
+    }
+}
+
+impl<const N: usize> Debug for SizedBoard<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        self.display(f, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_vec_to_sized_board() {
+        // Given
+        #[rustfmt::skip]
+        let vec_board: Vec<u16> = vec![
+            0, 2, 4,
+            8, 0, 0,
+            32768, 16, 2,
+        ];
+
+        // When
+        let board = Board3::from(vec_board.clone());
+
+        // Then
+        assert_eq!(vec_board, Vec::from(board));
+    }
+
+    #[test]
+    fn should_get_rows_and_columns() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board3::from(vec![
+            0, 2, 4,
+            8, 0, 0,
+            0, 16, 2,
+        ]);
+
+        // When
+        let rows = board.rows();
+        let columns = board.columns();
+
+        // Then
+        assert_eq!([0x012, 0x300, 0x041], rows);
+        assert_eq!([0x030, 0x104, 0x201], columns);
+    }
+
+    #[test]
+    fn should_move_left_on_3x3_board() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board3::from(vec![
+            2, 2, 4,
+            0, 8, 8,
+            4, 0, 4,
+        ]);
+
+        // When
+        let moved_board = board.move_to(Direction::Left);
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board3::from(vec![
+            4, 4, 0,
+            16, 0, 0,
+            8, 0, 0,
+        ]);
+        assert_eq!(expected_board, moved_board);
+    }
+
+    #[test]
+    fn should_move_down_on_5x5_board() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board5::from(vec![
+            2, 0, 0, 0, 8,
+            2, 0, 4, 0, 0,
+            0, 0, 0, 0, 8,
+            0, 0, 4, 0, 0,
+            4, 2, 0, 0, 16,
+        ]);
+
+        // When
+        let moved_board = board.move_to(Direction::Down);
+
+        // Then
+        #[rustfmt::skip]
+        let expected_board = Board5::from(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            4, 0, 0, 0, 16,
+            4, 2, 8, 0, 16,
+        ]);
+        assert_eq!(expected_board, moved_board);
+    }
+
+    #[test]
+    fn should_display_sized_board_for_debug() {
+        // Given
+        #[rustfmt::skip]
+        let board = Board3::from(vec![
+            0, 2, 4,
+            8, 0, 0,
+            0, 16, 2048,
+        ]);
+
+        // When
+        let displayed_board = format!("{:?}", board);
+
+        // Then
+        let expected_display = "
+╔═══════╦═══════╦═══════╗
+║       ║     2 ║     4 ║
+╠═══════╬═══════╬═══════╣
+║     8 ║       ║       ║
+╠═══════╬═══════╬═══════╣
+║       ║    16 ║  2048 ║
+╚═══════╩═══════╩═══════╝
+";
+        assert_eq!(expected_display, displayed_board);
+    }
+}
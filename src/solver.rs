@@ -1,33 +1,153 @@
 use crate::board::{Board, Direction};
+use crate::error::{Error, ErrorKind};
 use crate::evaluators::{BoardEvaluator, MonotonicityEvaluator, PrecomputedBoardEvaluator};
-use fnv::FnvHashMap;
-use std::cmp::max;
+use crate::game::GameBuilder;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, Ordering};
+use core::convert::TryFrom;
+use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use fnv::FnvBuildHasher;
+use log::{debug, trace};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+/// Depth at which the time-bounded search stops deepening, regardless of the remaining time
+#[cfg(feature = "std")]
+const MAX_TIMED_SEARCH_DEPTH: usize = 16;
+
+/// Hash map of the transposition table, which is also available without `std`
+type FnvHashMap<K, V> = hashbrown::HashMap<K, V, FnvBuildHasher>;
 
 pub struct Solver {
-    board_evaluator: Box<dyn BoardEvaluator>,
-    proba_2: f32,
-    proba_4: f32,
-    base_max_search_depth: usize,
-    min_branch_proba: f32,
-    transposition_table: FnvHashMap<Board, (f32, f32)>,
+    board_evaluator: Arc<dyn BoardEvaluator>,
+    /// same evaluator as `board_evaluator`, set when it can be shared between the threads of the
+    /// parallel search
+    #[cfg(feature = "parallel")]
+    shared_evaluator: Option<Arc<dyn BoardEvaluator + Send + Sync>>,
+    config: SearchConfig,
+    transposition_table: FnvHashMap<Board, TableEntry>,
+    /// incremented at each search, used to evict entries of previous searches
+    generation: u32,
+    /// last generation at which entries of previous searches have been evicted
+    evicted_generation: u32,
+    /// max search depth of the current search
+    search_depth: usize,
+    stats: SearchStats,
+}
+
+/// Statistics about the last search performed by the `Solver`
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SearchStats {
+    /// number of evaluated chance nodes, i.e. boards on which a new tile must be populated
+    pub nodes: u64,
+    /// number of chance nodes whose value has been found in the transposition table
+    pub cache_hits: u64,
+    /// number of chance nodes whose value could not be found in the transposition table
+    pub cache_misses: u64,
+    /// maximum number of moves between the root of the search and an evaluated board
+    pub max_depth_reached: usize,
+}
+
+impl SearchStats {
+    #[cfg(feature = "parallel")]
+    fn accumulate(&mut self, other: SearchStats) {
+        self.nodes += other.nodes;
+        self.cache_hits += other.cache_hits;
+        self.cache_misses += other.cache_misses;
+        self.max_depth_reached = max(self.max_depth_reached, other.max_depth_reached);
+    }
+}
+
+/// Final state of a game played by the `Solver`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GameResult {
+    pub final_board: Board,
+    pub score: u32,
+    /// number of moves played
+    pub moves: usize,
+    pub max_tile: u16,
+    pub reached_2048: bool,
+}
+
+/// Move played by the `Solver` during a game
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MoveEvent {
+    pub board_before: Board,
+    pub direction: Direction,
+    /// board obtained after the move and the population of a new tile
+    pub board_after: Board,
+    /// index and value of the populated tile
+    pub spawned_tile: Option<(u8, u16)>,
+    /// score of the game after the move
+    pub score: u32,
+    /// statistics of the search which selected the move
+    pub stats: SearchStats,
+}
+
+/// Entry of the transposition table
+#[derive(Clone, Copy)]
+struct TableEntry {
+    value: f32,
+    /// probability of the branch in which the value has been computed
+    proba: f32,
+    /// remaining search depth with which the value has been computed
+    depth: usize,
+    /// generation of the last search which used this entry
+    generation: u32,
 }
 
+/// Builds a `Solver`, which searches serially unless built with `parallel_board_evaluator`
 pub struct SolverBuilder {
-    board_evaluator: Box<dyn BoardEvaluator>,
-    proba_4: f32,
+    board_evaluator: Arc<dyn BoardEvaluator>,
+    #[cfg(feature = "parallel")]
+    shared_evaluator: Option<Arc<dyn BoardEvaluator + Send + Sync>>,
+    config: SearchConfig,
+}
+
+/// Options of the search, shared by a `Solver` and the workers of its parallel search
+#[derive(Clone)]
+struct SearchConfig {
+    spawn_distribution: SpawnDistribution,
     base_max_search_depth: usize,
+    depth_schedule: DepthSchedule,
     min_branch_proba: f32,
+    /// minimum branch probability as a function of the remaining depth, overriding
+    /// `min_branch_proba` when provided
+    min_branch_proba_by_depth: Option<fn(usize) -> f32>,
+    use_symmetries: bool,
+    early_chance_cutoff: bool,
+    /// whether the tiles are placed by an adversary minimizing the evaluation
+    adversarial: bool,
+    /// whether the gameover penalty is multiplied by the remaining depth at which the game is over
+    scale_gameover_penalty: bool,
+    max_table_entries: Option<usize>,
 }
 
 impl Default for SolverBuilder {
     fn default() -> Self {
         Self {
-            board_evaluator: Box::new(PrecomputedBoardEvaluator::new(
+            board_evaluator: Arc::new(PrecomputedBoardEvaluator::new(
                 MonotonicityEvaluator::default(),
             )),
-            proba_4: 0.1,
-            base_max_search_depth: 3,
-            min_branch_proba: 0.1 * 0.1,
+            #[cfg(feature = "parallel")]
+            shared_evaluator: None,
+            config: SearchConfig {
+                spawn_distribution: SpawnDistribution::from_proba_4(0.1),
+                base_max_search_depth: 3,
+                depth_schedule: DepthSchedule::default(),
+                min_branch_proba: 0.1 * 0.1,
+                min_branch_proba_by_depth: None,
+                use_symmetries: false,
+                early_chance_cutoff: false,
+                adversarial: false,
+                scale_gameover_penalty: false,
+                max_table_entries: None,
+            },
         }
     }
 }
@@ -38,13 +158,40 @@ impl SolverBuilder {
     where
         T: BoardEvaluator + 'static,
     {
-        self.board_evaluator = Box::new(evaluator);
+        self.board_evaluator = Arc::new(evaluator);
+        #[cfg(feature = "parallel")]
+        {
+            self.shared_evaluator = None;
+        }
+        self
+    }
+
+    /// Sets the `BoardEvaluator` like `board_evaluator`, and makes the solver evaluate the
+    /// top-level moves concurrently, the evaluator being shared between threads
+    ///
+    /// The parallel search is opt-in: enabling the `parallel` feature alone does not change
+    /// the search, and a solver built with `board_evaluator` always searches serially.
+    #[cfg(feature = "parallel")]
+    pub fn parallel_board_evaluator<T>(mut self, evaluator: T) -> Self
+    where
+        T: BoardEvaluator + Send + Sync + 'static,
+    {
+        let evaluator = Arc::new(evaluator);
+        self.board_evaluator = Arc::clone(&evaluator) as Arc<dyn BoardEvaluator>;
+        self.shared_evaluator = Some(evaluator);
         self
     }
 
     /// Sets the probability weight associated to the draw of a 4 tile
     pub fn proba_4(mut self, proba_4: f32) -> Self {
-        self.proba_4 = proba_4;
+        self.config.spawn_distribution = SpawnDistribution::from_proba_4(proba_4);
+        self
+    }
+
+    /// Sets the distribution of the tiles which are populated after each move
+    /// This generalizes `proba_4` to variants of the game in which other tiles can be populated.
+    pub fn spawn_distribution(mut self, spawn_distribution: SpawnDistribution) -> Self {
+        self.config.spawn_distribution = spawn_distribution;
         self
     }
 
@@ -53,135 +200,868 @@ impl SolverBuilder {
     /// It is thus the max depth which will be used in easy configurations. The effective
     /// max depth will be higher for more difficult ones.
     pub fn base_max_search_depth(mut self, max_search_depth: usize) -> Self {
-        self.base_max_search_depth = max_search_depth;
+        self.config.base_max_search_depth = max_search_depth;
+        self
+    }
+
+    /// Sets the adjustments of the max search depth according to the max value of the board
+    pub fn depth_schedule(mut self, depth_schedule: DepthSchedule) -> Self {
+        self.config.depth_schedule = depth_schedule;
         self
     }
 
     /// Sets the minimum probability for a branch to be explored
     pub fn min_branch_proba(mut self, proba: f32) -> Self {
-        self.min_branch_proba = proba;
+        self.config.min_branch_proba = proba;
+        self
+    }
+
+    /// Sets the minimum probability for a branch to be explored as a function of the remaining
+    /// search depth, in place of the constant `min_branch_proba`
+    /// This allows, for instance, to explore the likely branches deeply while pruning the
+    /// unlikely ones close to the leaves.
+    pub fn min_branch_proba_by_depth(mut self, min_branch_proba: fn(usize) -> f32) -> Self {
+        self.config.min_branch_proba_by_depth = Some(min_branch_proba);
+        self
+    }
+
+    /// Sets whether or not symmetric boards, i.e. boards which can be obtained from one another
+    /// through rotations and reflections, share the same entry in the transposition table
+    /// This must only be enabled with a `BoardEvaluator` which gives the same evaluation to
+    /// symmetric boards. A `RowColumnEvaluator`, or a `PrecomputedBoardEvaluator` built from it,
+    /// does so when its row evaluation is invariant under row reversal, which is the case of
+    /// `EmptyTileEvaluator`, `AlignmentEvaluator`, `SmoothnessEvaluator`,
+    /// `MonotonicityEvaluator` and any `CombinedBoardEvaluator` of them.
+    pub fn use_symmetries(mut self, use_symmetries: bool) -> Self {
+        self.config.use_symmetries = use_symmetries;
+        self
+    }
+
+    /// Sets whether or not the exploration of the empty tiles of a board stops early, once the
+    /// total probability of the remaining tiles is below the minimum branch probability
+    /// The value of the board is then approximated by the average over the explored tiles. This
+    /// speeds up the search in crowded branches close to the probability threshold, at the cost
+    /// of a slightly less accurate evaluation, and thus of a slightly lower quality of the moves.
+    /// Boards with few empty tiles are not affected, as all their tiles carry a high probability.
+    pub fn early_chance_cutoff(mut self, early_chance_cutoff: bool) -> Self {
+        self.config.early_chance_cutoff = early_chance_cutoff;
+        self
+    }
+
+    /// Sets whether or not the new tiles are considered to be placed by an adversary
+    /// In adversarial mode, the value of a board on which a tile must be populated is the minimum
+    /// over all the possible placements, instead of their expectation. This allows to study the
+    /// worst-case survival of a strategy. Probabilities are ignored in this mode, so that the
+    /// search is only bounded by its depth.
+    pub fn adversarial(mut self, adversarial: bool) -> Self {
+        self.config.adversarial = adversarial;
+        self
+    }
+
+    /// Sets whether or not the gameover penalty is multiplied by the remaining search depth at
+    /// which the game is over
+    /// With a negative penalty, losing early is then penalized more than losing late, which
+    /// makes the solver delay the end of the game when it cannot be avoided.
+    pub fn scale_gameover_penalty(mut self, scale_gameover_penalty: bool) -> Self {
+        self.config.scale_gameover_penalty = scale_gameover_penalty;
+        self
+    }
+
+    /// Sets the maximum number of entries of the transposition table
+    /// The transposition table is kept from one move to the next. When a maximum number of
+    /// entries is set, the entries which have not been used during the current search are
+    /// evicted once this capacity is reached. Otherwise, the entries which have not been used
+    /// during the previous search are evicted at the beginning of each search.
+    pub fn max_table_entries(mut self, max_entries: usize) -> Self {
+        self.config.max_table_entries = Some(max_entries);
         self
     }
 
     pub fn build(self) -> Solver {
-        Solver {
-            board_evaluator: self.board_evaluator,
-            proba_2: 1. - self.proba_4,
-            proba_4: self.proba_4,
-            base_max_search_depth: self.base_max_search_depth,
-            min_branch_proba: self.min_branch_proba,
-            transposition_table: Default::default(),
+        #[allow(unused_mut)]
+        let mut solver = Solver::new(self.board_evaluator, self.config);
+        #[cfg(feature = "parallel")]
+        {
+            solver.shared_evaluator = self.shared_evaluator;
         }
+        solver
     }
 }
 
 impl Solver {
+    /// Returns a solver with an empty transposition table, which searches serially
+    fn new(board_evaluator: Arc<dyn BoardEvaluator>, config: SearchConfig) -> Self {
+        Self {
+            board_evaluator,
+            #[cfg(feature = "parallel")]
+            shared_evaluator: None,
+            config,
+            transposition_table: Default::default(),
+            generation: 0,
+            evicted_generation: 0,
+            search_depth: 0,
+            stats: SearchStats::default(),
+        }
+    }
+
     pub fn next_best_move(&mut self, board: Board) -> Option<Direction> {
+        self.next_best_move_scored(board).map(|(d, _)| d)
+    }
+
+    /// Returns the next best move like `next_best_move`, or an error when the evaluation of the
+    /// best move is NaN or infinite
+    /// `Ok(None)` is only returned when no move is possible.
+    pub fn try_next_best_move(&mut self, board: Board) -> Result<Option<Direction>, Error> {
+        match self.next_best_move_scored(board) {
+            Some((direction, score)) if !score.is_finite() => Err(Error::new(
+                ErrorKind::InvalidEvaluation,
+                format!(
+                    "Invalid evaluation {} of the best move {:?}",
+                    score, direction
+                ),
+            )),
+            best_move => Ok(best_move.map(|(direction, _)| direction)),
+        }
+    }
+
+    /// Returns the next best move along with its expected evaluation
+    pub fn next_best_move_scored(&mut self, board: Board) -> Option<(Direction, f32)> {
         let max_depth = self.compute_max_depth(board);
-        self.transposition_table = FnvHashMap::default();
-        self.eval_max(board, max_depth as usize, 1.0)
+        self.start_search(max_depth);
+        let best_move = self.eval_root(board, max_depth);
+        match best_move {
+            Some((direction, score)) => debug!(
+                "Best move: {:?}, score: {}, depth: {}",
+                direction, score, max_depth
+            ),
+            None => debug!("No move left, depth: {}", max_depth),
+        }
+        trace!(
+            "Search stats: nodes: {}, cache hits: {}, cache misses: {}, max depth reached: {}",
+            self.stats.nodes,
+            self.stats.cache_hits,
+            self.stats.cache_misses,
+            self.stats.max_depth_reached
+        );
+        best_move
+    }
+
+    /// Returns the next best move along with its expected evaluation and the expected score
+    /// gained over the search horizon
+    /// The expected score is the score of the chosen move followed by the moves maximizing the
+    /// expected score, until the max search depth is reached. Contrary to the evaluation, it is
+    /// expressed in game score units, which allows to compare the choice of the heuristic with a
+    /// score-maximizing objective.
+    pub fn next_best_move_with_expected_score(
+        &mut self,
+        board: Board,
+    ) -> Option<(Direction, f32, f32)> {
+        let (direction, value) = self.next_best_move_scored(board)?;
+        let (new_board, score_gained) = board.move_to_with_score(direction);
+        let expected_score = score_gained as f32
+            + self.expected_score_average(
+                new_board,
+                self.search_depth,
+                1.0,
+                &mut FnvHashMap::default(),
+            );
+        Some((direction, value, expected_score))
+    }
+
+    /// Returns the next best move like `next_best_move`, unless `should_cancel` is set during the
+    /// search
+    /// The flag is checked between the evaluations of the top-level moves, in which case the best
+    /// move found so far is returned. A legal move is returned even when the flag is set before
+    /// the search starts. The search is always performed serially.
+    pub fn next_best_move_cancelable(
+        &mut self,
+        board: Board,
+        should_cancel: &AtomicBool,
+    ) -> Option<Direction> {
+        let max_depth = self.compute_max_depth(board);
+        self.start_search(max_depth);
+        self.eval_max(board, max_depth, 1.0, None, Some(should_cancel))
             .map(|(d, _)| d)
     }
 
-    fn compute_max_depth(&self, board: Board) -> usize {
-        let adjustment_factor = match board.max_value() {
-            2048 => 4,
-            4096 => 2,
-            8192 => 2,
-            16384 => 1,
-            32768 => 0,
-            _ => 7,
+    /// Evaluates the board with the `BoardEvaluator` of the solver
+    pub fn evaluate(&self, board: Board) -> f32 {
+        self.board_evaluator.evaluate(board)
+    }
+
+    /// Returns the gameover penalty of the `BoardEvaluator` of the solver
+    pub fn gameover_penalty(&self) -> f32 {
+        self.board_evaluator.gameover_penalty()
+    }
+
+    /// Returns the statistics of the last search
+    pub fn last_search_stats(&self) -> SearchStats {
+        self.stats
+    }
+
+    /// Returns the max search depth used by the last search, after the adjustments of
+    /// `compute_max_depth`
+    pub fn last_search_depth(&self) -> usize {
+        self.search_depth
+    }
+
+    /// Returns the number of entries in the transposition table
+    /// With the `parallel` feature, `next_best_move` evaluates each top-level move with a
+    /// separate table which is dropped after the search, so that it does not fill this one.
+    pub fn table_len(&self) -> usize {
+        self.transposition_table.len()
+    }
+
+    /// Clears the transposition table and the statistics of the last search, so that the solver
+    /// can be reused for an independent game
+    /// The configuration and the evaluator of the solver are kept.
+    pub fn reset(&mut self) {
+        self.transposition_table = FnvHashMap::default();
+        self.generation = 0;
+        self.evicted_generation = 0;
+        self.search_depth = 0;
+        self.stats = SearchStats::default();
+    }
+
+    /// Prepares the transposition table and the statistics for a new search
+    fn start_search(&mut self, max_depth: usize) {
+        self.stats = SearchStats::default();
+        self.search_depth = max_depth;
+        if self.config.max_table_entries.is_none() {
+            let generation = self.generation;
+            self.transposition_table
+                .retain(|_, entry| entry.generation == generation);
+        }
+        self.generation += 1;
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn eval_root(&mut self, board: Board, max_depth: usize) -> Option<(Direction, f32)> {
+        self.eval_max(board, max_depth, 1.0, None, None)
+    }
+
+    /// Evaluates the legal moves concurrently, each one with its own transposition table, when
+    /// the solver has been built with a `parallel_board_evaluator`
+    /// The tables of the workers are not merged back into the table of the solver.
+    #[cfg(feature = "parallel")]
+    fn eval_root(&mut self, board: Board, max_depth: usize) -> Option<(Direction, f32)> {
+        let shared_evaluator = match &self.shared_evaluator {
+            Some(evaluator) => Arc::clone(evaluator),
+            None => return self.eval_max(board, max_depth, 1.0, None, None),
         };
+        let config = &self.config;
+        let search_depth = self.search_depth;
+        let results: Vec<_> = board
+            .legal_move_boards()
+            .into_par_iter()
+            .map(|(d, new_board)| {
+                // a `Solver` cannot be sent between threads, so each worker is built in its own
+                let mut worker = Solver::new(shared_evaluator.clone(), config.clone());
+                worker.search_depth = search_depth;
+                let score = worker.eval_average(new_board, max_depth, 1.0);
+                ((d, score), worker.stats)
+            })
+            .collect();
+        for (_, stats) in results.iter() {
+            self.stats.accumulate(*stats);
+        }
+        results
+            .into_iter()
+            .map(|(scored_move, _)| scored_move)
+            .max_by(cmp_scored_moves)
+    }
+
+    /// Returns the next best move using iterative deepening up to `max_depth`
+    /// The best move found at a given depth is evaluated first at the next depth. The returned
+    /// move is the same as the one of a direct search at `max_depth`.
+    pub fn next_best_move_iterative(
+        &mut self,
+        board: Board,
+        max_depth: usize,
+    ) -> Option<Direction> {
+        let mut best_move = None;
+        self.start_search(max_depth);
+        for depth in 1..=max_depth {
+            self.search_depth = depth;
+            best_move = Some(self.eval_max(board, depth, 1.0, best_move, None)?.0);
+        }
+        best_move
+    }
+
+    /// Returns the next best move found within the provided time budget
+    /// The search is performed with iterative deepening: the search depth starts at 1 and is
+    /// increased until the time budget is nearly exhausted. The returned move is the best move of
+    /// the deepest search which has been completed. The first iteration is always completed so
+    /// that a legal move is returned even with a very small budget.
+    #[cfg(feature = "std")]
+    pub fn next_best_move_timed(&mut self, board: Board, budget: Duration) -> Option<Direction> {
+        let start = Instant::now();
+        let mut best_move = None;
+        let mut depth = 1;
+        self.start_search(depth);
+        loop {
+            let iteration_start = Instant::now();
+            self.search_depth = depth;
+            let mut iteration_best_move: Option<(Direction, f32)> = None;
+            for direction in ordered_directions(best_move).iter() {
+                // the clock is only checked between top-level moves, and the partial result of
+                // an interrupted iteration is discarded
+                if best_move.is_some() && start.elapsed() >= budget {
+                    return best_move;
+                }
+                let new_board = board.move_to(*direction);
+                if board == new_board {
+                    continue;
+                }
+                let scored_move = (*direction, self.eval_average(new_board, depth, 1.0));
+                if iteration_best_move.map_or(true, |best| {
+                    cmp_scored_moves(&scored_move, &best) == Ordering::Greater
+                }) {
+                    iteration_best_move = Some(scored_move);
+                }
+            }
+            best_move = Some(iteration_best_move?.0);
+            // the next iteration is expected to last at least as long as this one
+            let next_iteration_end = start.elapsed() + iteration_start.elapsed();
+            if depth >= MAX_TIMED_SEARCH_DEPTH || next_iteration_end >= budget {
+                return best_move;
+            }
+            depth += 1;
+        }
+    }
+
+    /// Returns all the legal moves along with their expected evaluation, sorted from the best
+    /// move to the worst one
+    pub fn rank_moves(&mut self, board: Board) -> Vec<(Direction, f32)> {
+        let max_depth = self.compute_max_depth(board);
+        self.start_search(max_depth);
+        let mut moves: Vec<_> = board
+            .legal_move_boards()
+            .into_iter()
+            .map(|(d, new_board)| (d, self.eval_average(new_board, max_depth, 1.0)))
+            .collect();
+        moves.sort_by(|lhs, rhs| cmp_scored_moves(rhs, lhs));
+        moves
+    }
+
+    /// Returns the sequence of best moves expected from the provided board, up to `moves` moves
+    /// After each move, the most probable tile is populated on the first empty tile, instead of
+    /// a random one, so that the returned sequence is deterministic. The sequence is shorter
+    /// than `moves` when the game is over before.
+    pub fn principal_variation(&mut self, board: Board, moves: usize) -> Vec<Direction> {
+        let mut board = board;
+        let mut variation = Vec::with_capacity(moves);
+        while variation.len() < moves {
+            let direction = match self.next_best_move(board) {
+                Some(direction) => direction,
+                None => break,
+            };
+            variation.push(direction);
+            board = board.move_to(direction);
+            if let Some(idx) = board.empty_tiles_indices().next() {
+                board = board
+                    .set_value_by_exponent(idx, self.config.spawn_distribution.most_probable());
+            }
+        }
+        variation
+    }
+
+    /// Plays a full game from the provided board until no move is possible, and returns the
+    /// final state of the game
+    /// New tiles are populated with a random number generator seeded with `seed`, a 4 tile
+    /// being populated with probability `proba_4`, so that the game is reproducible.
+    pub fn play_out(&mut self, initial: Board, proba_4: f32, seed: u64) -> GameResult {
+        self.play_out_with_callback(initial, proba_4, seed, |_| {})
+    }
+
+    /// Plays a full game like `play_out`, calling `on_move` after each move
+    pub fn play_out_with_callback(
+        &mut self,
+        initial: Board,
+        proba_4: f32,
+        seed: u64,
+        mut on_move: impl FnMut(&MoveEvent),
+    ) -> GameResult {
+        let mut game = GameBuilder::default()
+            .initial_board(initial)
+            .proba_4(proba_4)
+            .seed(seed)
+            .build();
+        let mut moves = 0;
+        while let Some(direction) = self.next_best_move(game.board) {
+            let board_before = game.board;
+            game.step(direction);
+            moves += 1;
+            on_move(&MoveEvent {
+                board_before,
+                direction,
+                board_after: game.board,
+                spawned_tile: game.spawn_log().spawns().last().copied(),
+                score: game.score,
+                stats: self.stats,
+            });
+        }
+        let max_tile = game.board.max_value();
+        GameResult {
+            final_board: game.board,
+            score: game.score,
+            moves,
+            max_tile,
+            reached_2048: max_tile >= 2048,
+        }
+    }
+
+    fn compute_max_depth(&self, board: Board) -> usize {
+        let adjustment_factor = self.config.depth_schedule.adjustment(board.max_value())
+            + self
+                .config
+                .depth_schedule
+                .empty_tiles_adjustment(board.count_empty_tiles());
         max(
-            self.base_max_search_depth as isize,
+            self.config.base_max_search_depth as isize,
             board.count_distinct_tiles() as isize - adjustment_factor,
         ) as usize
     }
 
+    /// Returns the best move along with its evaluation, `first_direction` being evaluated first
+    /// when provided
+    /// When `should_cancel` is set, the remaining moves are not evaluated and the best move found
+    /// so far is returned. If no move has been evaluated yet, the first legal move is returned
+    /// along with the static evaluation of the resulting board.
     fn eval_max(
         &mut self,
         board: Board,
         remaining_depth: usize,
         branch_proba: f32,
+        first_direction: Option<Direction>,
+        should_cancel: Option<&AtomicBool>,
     ) -> Option<(Direction, f32)> {
-        Direction::all()
-            .iter()
-            .filter_map(|d| {
-                let new_board = board.move_to(*d);
-                if board == new_board {
-                    return None;
-                }
-                Some((
-                    *d,
-                    self.eval_average(new_board, remaining_depth, branch_proba),
-                ))
-            })
-            .max_by(|(_, lhs), (_, rhs)| lhs.partial_cmp(rhs).unwrap())
+        let mut best_move: Option<(Direction, f32)> = None;
+        for d in ordered_directions(first_direction).iter() {
+            let new_board = board.move_to(*d);
+            if board == new_board {
+                continue;
+            }
+            if should_cancel.map_or(false, |cancel| cancel.load(AtomicOrdering::Relaxed)) {
+                return best_move.or_else(|| Some((*d, self.board_evaluator.evaluate(new_board))));
+            }
+            let scored_move = (
+                *d,
+                self.eval_average(new_board, remaining_depth, branch_proba),
+            );
+            if best_move.map_or(true, |best| {
+                cmp_scored_moves(&scored_move, &best) == Ordering::Greater
+            }) {
+                best_move = Some(scored_move);
+            }
+        }
+        best_move
     }
 
     fn eval_average(&mut self, board: Board, remaining_depth: usize, branch_proba: f32) -> f32 {
-        if remaining_depth == 0 || branch_proba < self.min_branch_proba {
+        self.stats.nodes += 1;
+        self.stats.max_depth_reached = max(
+            self.stats.max_depth_reached,
+            self.search_depth - remaining_depth,
+        );
+        let min_branch_proba = self.min_branch_proba(remaining_depth);
+        if remaining_depth == 0 || branch_proba < min_branch_proba {
             return self.board_evaluator.evaluate(board);
         }
 
-        if let Some((cached_value, cached_proba)) = self.transposition_table.get(&board) {
-            if *cached_proba >= branch_proba {
-                return *cached_value;
+        let table_key = if self.config.use_symmetries {
+            board.canonical()
+        } else {
+            board
+        };
+        let generation = self.generation;
+        let scale_gameover_penalty = self.config.scale_gameover_penalty;
+        if let Some(entry) = self.transposition_table.get_mut(&table_key) {
+            // the cached value can only be used if it was computed with a deeper search, or with
+            // the same depth when the gameover penalty depends on it
+            let depth_matches = if scale_gameover_penalty {
+                entry.depth == remaining_depth
+            } else {
+                entry.depth >= remaining_depth
+            };
+            if entry.proba >= branch_proba && depth_matches {
+                entry.generation = generation;
+                self.stats.cache_hits += 1;
+                return entry.value;
             }
         }
+        self.stats.cache_misses += 1;
+
+        if self.config.adversarial {
+            let min_score = self.eval_min(board, remaining_depth, branch_proba);
+            self.insert_table_entry(table_key, min_score, branch_proba, remaining_depth);
+            return min_score;
+        }
 
-        let empty_tiles_indices = board.empty_tiles_indices();
-        let nb_empty_tiles = board.count_empty_tiles() as f32;
-        let proba_2 = self.proba_2;
-        let proba_4 = self.proba_4;
-        let scores_sum: f32 = empty_tiles_indices
-            .map(|idx| {
-                let board_with_2 = board.set_value_by_exponent(idx, 1);
-                let board_with_4 = board.set_value_by_exponent(idx, 2);
-                let max_score_2 = self
+        let empty_tiles = board.empty_tiles_indices();
+        let nb_empty_tiles = empty_tiles.len() as f32;
+        let mut scores_sum = 0.;
+        let mut nb_explored_tiles = 0.;
+        for idx in empty_tiles {
+            let remaining_proba =
+                branch_proba * (nb_empty_tiles - nb_explored_tiles) / nb_empty_tiles;
+            if self.config.early_chance_cutoff
+                && nb_explored_tiles > 0.
+                && remaining_proba < min_branch_proba
+            {
+                break;
+            }
+            let mut tile_score = 0.;
+            for outcome_idx in 0..self.config.spawn_distribution.outcomes.len() {
+                let (exponent, proba) = self.config.spawn_distribution.outcomes[outcome_idx];
+                let max_score = self
                     .eval_max(
-                        board_with_2,
+                        board.set_value_by_exponent(idx, exponent),
                         remaining_depth - 1,
-                        branch_proba * proba_2 / nb_empty_tiles,
+                        branch_proba * proba / nb_empty_tiles,
+                        None,
+                        None,
                     )
                     .map(|(_, score)| score)
-                    .unwrap_or_else(|| self.board_evaluator.gameover_penalty());
-                let max_score_4 = self
+                    .unwrap_or_else(|| self.gameover_value(remaining_depth));
+                tile_score += max_score * proba;
+            }
+            scores_sum += tile_score;
+            nb_explored_tiles += 1.;
+        }
+        let average = scores_sum / nb_explored_tiles;
+        self.insert_table_entry(table_key, average, branch_proba, remaining_depth);
+        average
+    }
+
+    /// Returns the minimum value over all the possible placements of a new tile, as chosen by an
+    /// adversary
+    fn eval_min(&mut self, board: Board, remaining_depth: usize, branch_proba: f32) -> f32 {
+        let mut min_score = f32::INFINITY;
+        for idx in board.empty_tiles_indices() {
+            for outcome_idx in 0..self.config.spawn_distribution.outcomes.len() {
+                let (exponent, _) = self.config.spawn_distribution.outcomes[outcome_idx];
+                let max_score = self
                     .eval_max(
-                        board_with_4,
+                        board.set_value_by_exponent(idx, exponent),
                         remaining_depth - 1,
-                        branch_proba * proba_4 / nb_empty_tiles,
+                        branch_proba,
+                        None,
+                        None,
                     )
                     .map(|(_, score)| score)
-                    .unwrap_or_else(|| self.board_evaluator.gameover_penalty());
-                max_score_2 * proba_2 + max_score_4 * proba_4
-            })
-            .sum();
-        let average = scores_sum / nb_empty_tiles as f32;
-        self.transposition_table
-            .insert(board, (average, branch_proba));
+                    .unwrap_or_else(|| self.gameover_value(remaining_depth));
+                min_score = min_score.min(max_score);
+            }
+        }
+        min_score
+    }
+
+    /// Returns the expected score gained from a board on which a tile must be populated
+    /// The expected scores are cached in `score_table`, like the evaluations are in the
+    /// transposition table.
+    fn expected_score_average(
+        &self,
+        board: Board,
+        remaining_depth: usize,
+        branch_proba: f32,
+        score_table: &mut FnvHashMap<Board, TableEntry>,
+    ) -> f32 {
+        if remaining_depth == 0 || branch_proba < self.min_branch_proba(remaining_depth) {
+            return 0.;
+        }
+        let table_key = if self.config.use_symmetries {
+            board.canonical()
+        } else {
+            board
+        };
+        if let Some(entry) = score_table.get(&table_key) {
+            // the expected score grows with the depth, so only entries of the same depth are used
+            if entry.proba >= branch_proba && entry.depth == remaining_depth {
+                return entry.value;
+            }
+        }
+        let empty_tiles = board.empty_tiles_indices();
+        let nb_empty_tiles = empty_tiles.len() as f32;
+        let mut scores_sum = 0.;
+        for idx in empty_tiles {
+            for (exponent, proba) in self.config.spawn_distribution.outcomes.iter() {
+                scores_sum += proba
+                    * self.expected_score_max(
+                        board.set_value_by_exponent(idx, *exponent),
+                        remaining_depth - 1,
+                        branch_proba * proba / nb_empty_tiles,
+                        score_table,
+                    );
+            }
+        }
+        let average = scores_sum / nb_empty_tiles;
+        let entry = TableEntry {
+            value: average,
+            proba: branch_proba,
+            depth: remaining_depth,
+            generation: self.generation,
+        };
+        score_table.insert(table_key, entry);
         average
     }
+
+    /// Returns the maximum expected score gained over the legal moves, which is 0 when the game
+    /// is over
+    fn expected_score_max(
+        &self,
+        board: Board,
+        remaining_depth: usize,
+        branch_proba: f32,
+        score_table: &mut FnvHashMap<Board, TableEntry>,
+    ) -> f32 {
+        Direction::all()
+            .iter()
+            .filter_map(|d| {
+                let (new_board, score_gained) = board.move_to_with_score(*d);
+                if new_board == board {
+                    return None;
+                }
+                Some(
+                    score_gained as f32
+                        + self.expected_score_average(
+                            new_board,
+                            remaining_depth,
+                            branch_proba,
+                            score_table,
+                        ),
+                )
+            })
+            .fold(0., f32::max)
+    }
+
+    fn min_branch_proba(&self, remaining_depth: usize) -> f32 {
+        self.config
+            .min_branch_proba_by_depth
+            .map_or(self.config.min_branch_proba, |f| f(remaining_depth))
+    }
+
+    /// Returns the value of a board on which the game is over, found while evaluating a board
+    /// with `remaining_depth` on which a tile must be populated
+    fn gameover_value(&self, remaining_depth: usize) -> f32 {
+        let penalty = self.board_evaluator.gameover_penalty();
+        if self.config.scale_gameover_penalty {
+            penalty * remaining_depth as f32
+        } else {
+            penalty
+        }
+    }
+
+    fn insert_table_entry(&mut self, key: Board, value: f32, proba: f32, depth: usize) {
+        if let Some(max_entries) = self.config.max_table_entries {
+            let generation = self.generation;
+            if self.transposition_table.len() >= max_entries
+                && !self.transposition_table.contains_key(&key)
+            {
+                // entries of previous searches are evicted at most once per search
+                if self.evicted_generation < generation {
+                    self.transposition_table
+                        .retain(|_, entry| entry.generation == generation);
+                    self.evicted_generation = generation;
+                }
+                if self.transposition_table.len() >= max_entries {
+                    return;
+                }
+            }
+        }
+        let entry = TableEntry {
+            value,
+            proba,
+            depth,
+            generation: self.generation,
+        };
+        self.transposition_table.insert(key, entry);
+    }
+}
+
+/// Adjustments of the max search depth according to the max value of the board
+/// The max search depth of a board is its number of distinct tiles minus the adjustment, and
+/// at least the base max search depth of the `Solver`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DepthSchedule {
+    /// adjustments associated to specific max values
+    adjustments: Vec<(u16, isize)>,
+    /// adjustment used for the max values which are not listed
+    default_adjustment: isize,
+    /// additional adjustment per empty tile above half of the board
+    empty_tiles_weight: f32,
+}
+
+impl DepthSchedule {
+    pub fn new(adjustments: Vec<(u16, isize)>, default_adjustment: isize) -> Self {
+        Self {
+            adjustments,
+            default_adjustment,
+            empty_tiles_weight: 0.,
+        }
+    }
+
+    /// Sets the weight of the secondary adjustment based on the number of empty tiles
+    /// The search gets shallower by `weight` per empty tile above 8, and deeper by `weight` per
+    /// missing empty tile below 8, the adjustment being rounded to the nearest integer. This
+    /// avoids spending time on searching deeply boards with many empty tiles, on which most
+    /// moves are safe. The weight is 0 by default.
+    pub fn with_empty_tiles_weight(mut self, weight: f32) -> Self {
+        self.empty_tiles_weight = weight;
+        self
+    }
+
+    fn empty_tiles_adjustment(&self, nb_empty_tiles: usize) -> isize {
+        libm::roundf(self.empty_tiles_weight * (nb_empty_tiles as f32 - 8.)) as isize
+    }
+
+    fn adjustment(&self, max_value: u16) -> isize {
+        self.adjustments
+            .iter()
+            .find(|(value, _)| *value == max_value)
+            .map(|(_, adjustment)| *adjustment)
+            .unwrap_or(self.default_adjustment)
+    }
+}
+
+impl Default for DepthSchedule {
+    fn default() -> Self {
+        Self::new(
+            vec![(2048, 4), (4096, 2), (8192, 2), (16384, 1), (32768, 0)],
+            7,
+        )
+    }
+}
+
+/// Distribution of the tiles which are populated after each move
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpawnDistribution {
+    /// exponents of the populated tiles along with their probability
+    outcomes: Vec<(u64, f32)>,
+}
+
+impl SpawnDistribution {
+    /// Builds a distribution from the exponents of the possible tiles along with their
+    /// probability weights, which are normalized so that they sum to one
+    /// An error is returned when there is no outcome, when an exponent is not between `1` and
+    /// `15`, or when a weight is negative or the weights do not sum to a positive finite number.
+    pub fn new(outcomes: Vec<(u64, f32)>) -> Result<Self, Error> {
+        if let Some((exponent, _)) = outcomes.iter().find(|(e, _)| *e == 0 || *e > 15) {
+            return Err(Error::new(
+                ErrorKind::InvalidSpawnDistribution,
+                format!("Invalid tile exponent {}", exponent),
+            ));
+        }
+        if let Some((_, weight)) = outcomes.iter().find(|(_, w)| w.is_nan() || *w < 0.) {
+            return Err(Error::new(
+                ErrorKind::InvalidSpawnDistribution,
+                format!("Invalid probability weight {}", weight),
+            ));
+        }
+        let total_weight: f32 = outcomes.iter().map(|(_, weight)| weight).sum();
+        if !(total_weight > 0. && total_weight.is_finite()) {
+            return Err(Error::new(
+                ErrorKind::InvalidSpawnDistribution,
+                format!("Invalid total probability weight {}", total_weight),
+            ));
+        }
+        Ok(Self {
+            outcomes: outcomes
+                .into_iter()
+                .map(|(exponent, weight)| (exponent, weight / total_weight))
+                .collect(),
+        })
+    }
+
+    /// Builds the distribution of the standard game, in which a 4 tile is populated with
+    /// probability `proba_4` and a 2 tile otherwise
+    pub fn from_proba_4(proba_4: f32) -> Self {
+        Self {
+            outcomes: vec![(1, 1. - proba_4), (2, proba_4)],
+        }
+    }
+
+    /// Returns the exponents of the possible tiles along with their probability
+    pub fn outcomes(&self) -> &[(u64, f32)] {
+        &self.outcomes
+    }
+
+    /// Returns the exponent of the most probable tile
+    fn most_probable(&self) -> u64 {
+        self.outcomes
+            .iter()
+            .max_by(|lhs, rhs| lhs.1.partial_cmp(&rhs.1).unwrap())
+            .map(|(exponent, _)| *exponent)
+            .unwrap()
+    }
+}
+
+/// Returns the next best move for the board given as its 16 tile values, using a default `Solver`
+/// This entry point only relies on plain types, so that it can be exposed to front-ends such as
+/// WebAssembly ones through `wasm-bindgen`. `None` is returned when the values do not represent
+/// a valid board or when no move is possible.
+pub fn solve(board_values: &[u16]) -> Option<Direction> {
+    let board = Board::try_from(board_values).ok()?;
+    SolverBuilder::default().build().next_best_move(board)
+}
+
+/// Returns the four directions, starting with `first_direction` when provided
+fn ordered_directions(first_direction: Option<Direction>) -> [Direction; 4] {
+    let mut directions = *Direction::all();
+    if let Some(first_direction) = first_direction {
+        let idx = directions
+            .iter()
+            .position(|d| *d == first_direction)
+            .unwrap();
+        directions[..=idx].rotate_right(1);
+    }
+    directions
+}
+
+/// Compares scored moves by score, ties being broken by the position of the direction in
+/// `Direction::all()` so that the best move does not depend on the order of evaluation
+fn cmp_scored_moves(lhs: &(Direction, f32), rhs: &(Direction, f32)) -> Ordering {
+    cmp_scores(lhs.1, rhs.1).then((lhs.0 as u8).cmp(&(rhs.0 as u8)))
+}
+
+/// Compares scores, NaN being considered lower than any other score
+pub(crate) fn cmp_scores(lhs: f32, rhs: f32) -> Ordering {
+    match (lhs.is_nan(), rhs.is_nan()) {
+        (false, false) => lhs.partial_cmp(&rhs).unwrap(),
+        (lhs_is_nan, rhs_is_nan) => rhs_is_nan.cmp(&lhs_is_nan),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_next_best_move() {
-        // Given
-        struct DummyEvaluator;
-        impl BoardEvaluator for DummyEvaluator {
-            fn evaluate(&self, board: Board) -> f32 {
-                board.max_value() as f32 / 32768.
-            }
+    struct DummyEvaluator;
+    impl BoardEvaluator for DummyEvaluator {
+        fn evaluate(&self, board: Board) -> f32 {
+            board.max_value() as f32 / 32768.
+        }
 
-            fn gameover_penalty(&self) -> f32 {
-                0.
-            }
+        fn gameover_penalty(&self) -> f32 {
+            0.
+        }
+    }
+
+    /// Evaluates every board to 0, so that only the gameover penalty matters
+    struct ZeroEvaluator;
+    impl BoardEvaluator for ZeroEvaluator {
+        fn evaluate(&self, _board: Board) -> f32 {
+            0.
+        }
+
+        fn gameover_penalty(&self) -> f32 {
+            -1.
         }
+    }
 
+    #[test]
+    fn test_next_best_move() {
+        // Given
         let mut solver = SolverBuilder::default()
             .board_evaluator(DummyEvaluator {})
             .base_max_search_depth(2)
@@ -201,4 +1081,934 @@ mod tests {
         // Then
         assert_eq!(Some(Direction::Down), direction);
     }
+
+    #[test]
+    fn test_next_best_move_scored() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .base_max_search_depth(2)
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            4, 4, 0, 4,
+            16, 0, 0, 2,
+            0, 8, 0, 16,
+            0, 8, 0, 16,
+        ]);
+
+        // When
+        let scored_move = solver.next_best_move_scored(board);
+
+        // Then
+        let (direction, score) = scored_move.unwrap();
+        assert!(score.is_finite());
+        assert_eq!(solver.next_best_move(board), Some(direction));
+    }
+
+    #[test]
+    fn test_next_best_move_with_expected_score() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .base_max_search_depth(2)
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            4, 4, 0, 4,
+            16, 0, 0, 2,
+            0, 8, 0, 16,
+            0, 8, 0, 16,
+        ]);
+        #[rustfmt::skip]
+        let game_over_board: Board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+
+        // When
+        let scored_move = solver.next_best_move_with_expected_score(board);
+        let game_over_expected_score =
+            solver.expected_score_max(game_over_board, 2, 1.0, &mut FnvHashMap::default());
+
+        // Then
+        let (direction, value, expected_score) = scored_move.unwrap();
+        assert_eq!(
+            solver.next_best_move_scored(board),
+            Some((direction, value))
+        );
+        assert!(expected_score >= 0.);
+        assert!(expected_score >= board.move_to_with_score(direction).1 as f32);
+        assert_eq!(0., game_over_expected_score);
+    }
+
+    #[test]
+    fn test_next_best_move_cancelable() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(PrecomputedBoardEvaluator::new(MonotonicityEvaluator {
+                gameover_penalty: -1000.,
+                monotonicity_power: 2,
+            }))
+            .base_max_search_depth(8)
+            .min_branch_proba(0.)
+            .build();
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 0, 8,
+            4, 2, 0, 0,
+            0, 0, 0, 0,
+            16, 0, 2, 0,
+        ]);
+        let should_cancel = AtomicBool::new(true);
+
+        // When
+        let next_move = solver.next_best_move_cancelable(board, &should_cancel);
+
+        // Then
+        let next_move = next_move.unwrap();
+        assert_ne!(board, board.move_to(next_move));
+        assert_eq!(0, solver.last_search_stats().nodes);
+    }
+
+    #[test]
+    fn test_solve() {
+        // Given
+        #[rustfmt::skip]
+        let board_values = [
+            2, 0, 0, 0,
+            2, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        #[rustfmt::skip]
+        let game_over_values = [
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ];
+
+        // When
+        let next_move = solve(&board_values);
+        let invalid_board_move = solve(&[2, 4, 3]);
+        let game_over_move = solve(&game_over_values);
+
+        // Then
+        let board = Board::try_from(&board_values[..]).unwrap();
+        assert_eq!(
+            SolverBuilder::default().build().next_best_move(board),
+            next_move
+        );
+        assert!(next_move.is_some());
+        assert_eq!(None, invalid_board_move);
+        assert_eq!(None, game_over_move);
+    }
+
+    #[test]
+    fn test_adversarial_solver() {
+        // Given
+        let build_solver = |adversarial| {
+            SolverBuilder::default()
+                .board_evaluator(PrecomputedBoardEvaluator::new(MonotonicityEvaluator {
+                    gameover_penalty: -1000.,
+                    monotonicity_power: 2,
+                }))
+                .base_max_search_depth(2)
+                .depth_schedule(DepthSchedule::new(vec![], 16))
+                .min_branch_proba(0.)
+                .adversarial(adversarial)
+                .build()
+        };
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 4, 8, 16,
+            4, 8, 16, 32,
+            2, 0, 4, 8,
+            0, 2, 0, 4,
+        ]);
+
+        // When
+        let (_, expectiminimax_value) = build_solver(false).next_best_move_scored(board).unwrap();
+        let (_, adversarial_value) = build_solver(true).next_best_move_scored(board).unwrap();
+
+        // Then
+        assert!(adversarial_value < expectiminimax_value);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // Given
+        let solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .build();
+        let board = Board::default().set_value(3, 2048);
+
+        // When
+        let evaluation = solver.evaluate(board);
+
+        // Then
+        assert_eq!(DummyEvaluator {}.evaluate(board), evaluation);
+        assert_eq!(0., solver.gameover_penalty());
+    }
+
+    #[test]
+    fn test_rank_moves() {
+        // Given
+        let mut solver = SolverBuilder::default().base_max_search_depth(2).build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            0, 0, 2, 4,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let ranked_moves = solver.rank_moves(board);
+
+        // Then
+        let directions: Vec<_> = ranked_moves.iter().map(|(d, _)| *d).collect();
+        assert_eq!(2, ranked_moves.len());
+        assert!(!directions.contains(&Direction::Up));
+        assert!(!directions.contains(&Direction::Right));
+        assert_eq!(solver.next_best_move(board), Some(directions[0]));
+        assert!(ranked_moves.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
+    #[test]
+    fn test_principal_variation() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .base_max_search_depth(2)
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            4, 4, 0, 4,
+            16, 0, 0, 2,
+            0, 8, 0, 16,
+            0, 8, 0, 16,
+        ]);
+
+        // When
+        let variation = solver.principal_variation(board, 5);
+
+        // Then
+        assert_eq!(5, variation.len());
+        assert_eq!(solver.next_best_move(board), Some(variation[0]));
+    }
+
+    #[test]
+    fn test_play_out() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .base_max_search_depth(1)
+            .min_branch_proba(0.1)
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            32, 64, 128, 256,
+            512, 2, 4, 8,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let result = solver.play_out(board, 0.1, 42);
+
+        // Then
+        assert!(result.final_board.is_game_over());
+        assert!(result.moves > 0);
+        assert_eq!(result.final_board.max_value(), result.max_tile);
+    }
+
+    #[test]
+    fn test_play_out_with_callback() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .base_max_search_depth(1)
+            .min_branch_proba(0.1)
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            32, 64, 128, 256,
+            512, 2, 4, 8,
+            0, 0, 0, 0,
+        ]);
+        let mut events = vec![];
+
+        // When
+        let result = solver.play_out_with_callback(board, 0.1, 42, |event| events.push(*event));
+
+        // Then
+        assert_eq!(result.moves, events.len());
+        let last_event = events.last().unwrap();
+        assert!(last_event.board_after.is_game_over());
+        assert_eq!(result.score, last_event.score);
+        assert_eq!(board, events[0].board_before);
+    }
+
+    #[test]
+    fn test_next_best_move_iterative() {
+        // Given
+        #[rustfmt::skip]
+        let boards = vec![
+            Board::from(vec![
+                4, 4, 0, 4,
+                16, 0, 0, 2,
+                0, 8, 0, 16,
+                0, 8, 0, 16,
+            ]),
+            Board::from(vec![
+                128, 256, 512, 2048,
+                64, 16, 8, 4,
+                16, 4, 8, 4,
+                4, 4, 8, 4,
+            ]),
+        ];
+        let mut solver = SolverBuilder::default().build();
+
+        for board in boards {
+            // When
+            let direction = solver.next_best_move_iterative(board, 3);
+
+            // Then
+            solver.transposition_table = FnvHashMap::default();
+            let direct_search_move = solver.eval_max(board, 3, 1.0, None, None).map(|(d, _)| d);
+            assert_eq!(direct_search_move, direction);
+        }
+    }
+
+    #[test]
+    fn test_next_best_move_iterative_keeps_table() {
+        // Given
+        let mut solver = SolverBuilder::default().build();
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            4, 4, 0, 4,
+            16, 0, 0, 2,
+            0, 8, 0, 16,
+            0, 8, 0, 16,
+        ]);
+        let direction = solver.next_best_move_iterative(board, 2);
+        let table_len = solver.table_len();
+
+        // When
+        let second_direction = solver.next_best_move_iterative(board, 2);
+
+        // Then
+        assert!(table_len > 0);
+        assert_eq!(table_len, solver.table_len());
+        assert_eq!(0, solver.last_search_stats().cache_misses);
+        assert_eq!(direction, second_direction);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_search_matches_serial_search() {
+        // Given
+        #[rustfmt::skip]
+        let boards = vec![
+            Board::from(vec![
+                4, 4, 0, 4,
+                16, 0, 0, 2,
+                0, 8, 0, 16,
+                0, 8, 0, 16,
+            ]),
+            Board::from(vec![
+                128, 256, 512, 2048,
+                64, 16, 8, 4,
+                16, 4, 8, 4,
+                4, 4, 8, 4,
+            ]),
+            Board::from(vec![
+                2, 4, 8, 16,
+                0, 0, 2, 4,
+                0, 0, 0, 0,
+                0, 0, 0, 0,
+            ]),
+        ];
+        let mut solver = SolverBuilder::default()
+            .parallel_board_evaluator(PrecomputedBoardEvaluator::new(
+                MonotonicityEvaluator::default(),
+            ))
+            .build();
+
+        for board in boards {
+            // When
+            let parallel_move = solver.next_best_move(board);
+
+            // Then
+            let max_depth = solver.compute_max_depth(board);
+            solver.transposition_table = FnvHashMap::default();
+            let serial_move = solver
+                .eval_max(board, max_depth, 1.0, None, None)
+                .map(|(d, _)| d);
+            assert_eq!(serial_move, parallel_move);
+        }
+    }
+
+    #[test]
+    fn test_max_table_entries() {
+        // Given
+        let mut solver = SolverBuilder::default().max_table_entries(50).build();
+        let mut game = crate::game::GameBuilder::default().seed(3).build();
+
+        for _ in 0..20 {
+            // When
+            let (direction, _) = solver.rank_moves(game.board)[0];
+            game.play(direction);
+            game.populate_new_tile();
+
+            // Then
+            assert!(solver.table_len() > 0);
+            assert!(solver.table_len() <= 50);
+        }
+    }
+
+    #[test]
+    fn test_persistent_transposition_table() {
+        // Given
+        let mut solver = SolverBuilder::default().build();
+        let mut reference_solver = SolverBuilder::default().build();
+        let mut game = crate::game::GameBuilder::default().seed(11).build();
+
+        for _ in 0..10 {
+            // When
+            let direction = solver.next_best_move(game.board);
+
+            // Then
+            reference_solver.transposition_table = FnvHashMap::default();
+            assert_eq!(reference_solver.next_best_move(game.board), direction);
+            game.play(direction.unwrap());
+            game.populate_new_tile();
+        }
+    }
+
+    thread_local! {
+        static DEBUG_RECORDS_COUNT: std::cell::Cell<usize> = std::cell::Cell::new(0);
+    }
+
+    /// Logger counting the debug records emitted by the current thread
+    struct CapturingLogger;
+    impl log::Log for CapturingLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Debug
+        }
+
+        fn log(&self, record: &log::Record) {
+            if record.level() == log::Level::Debug {
+                DEBUG_RECORDS_COUNT.with(|count| count.set(count.get() + 1));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURING_LOGGER: CapturingLogger = CapturingLogger;
+
+    #[test]
+    fn test_log_decisions() {
+        // Given
+        let _ = log::set_logger(&CAPTURING_LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+        let mut solver = SolverBuilder::default().base_max_search_depth(1).build();
+        let mut game = crate::game::GameBuilder::default().seed(3).build();
+
+        for _ in 0..5 {
+            // When
+            let count_before = DEBUG_RECORDS_COUNT.with(|count| count.get());
+            let direction = solver.next_best_move(game.board).unwrap();
+
+            // Then
+            assert!(DEBUG_RECORDS_COUNT.with(|count| count.get()) > count_before);
+            game.play(direction);
+            game.populate_new_tile();
+        }
+    }
+
+    #[test]
+    fn test_search_stats() {
+        // Given
+        let mut solver = SolverBuilder::default().base_max_search_depth(1).build();
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 4, 0,
+        ]);
+
+        // When
+        solver.next_best_move(board);
+        let first_stats = solver.last_search_stats();
+        solver = SolverBuilder::default().base_max_search_depth(2).build();
+        solver.next_best_move(board);
+        let second_stats = solver.last_search_stats();
+
+        // Then
+        assert_eq!(0, first_stats.cache_hits);
+        assert_eq!(1, first_stats.max_depth_reached);
+        assert!(first_stats.nodes > 0);
+        assert!(second_stats.cache_hits > 0);
+        assert_eq!(2, second_stats.max_depth_reached);
+    }
+
+    #[test]
+    fn test_early_chance_cutoff() {
+        // Given
+        #[rustfmt::skip]
+        let crowded_board: Board = Board::from(vec![
+            128, 256, 512, 2048,
+            64, 16, 8, 0,
+            16, 4, 8, 4,
+            4, 2, 0, 4,
+        ]);
+        #[rustfmt::skip]
+        let empty_board: Board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 4, 0,
+        ]);
+        let mut solver = SolverBuilder::default().early_chance_cutoff(true).build();
+        let mut reference_solver = SolverBuilder::default().build();
+
+        // When
+        solver.start_search(2);
+        let crowded_board_value = solver.eval_average(crowded_board, 2, 1.0);
+        let empty_board_move = solver.next_best_move(empty_board);
+        let cutoff_nodes = solver.last_search_stats().nodes;
+
+        // Then
+        reference_solver.start_search(2);
+        let reference_value = reference_solver.eval_average(crowded_board, 2, 1.0);
+        assert_eq!(reference_value, crowded_board_value);
+        reference_solver.next_best_move(empty_board);
+        assert_ne!(empty_board, empty_board.move_to(empty_board_move.unwrap()));
+        assert!(cutoff_nodes < reference_solver.last_search_stats().nodes);
+    }
+
+    #[test]
+    fn test_three_outcomes_spawn_distribution() {
+        // Given
+        struct ConstantEvaluator;
+        impl BoardEvaluator for ConstantEvaluator {
+            fn evaluate(&self, _board: Board) -> f32 {
+                1.
+            }
+
+            fn gameover_penalty(&self) -> f32 {
+                1.
+            }
+        }
+        let spawn_distribution = SpawnDistribution::new(vec![(1, 8.), (2, 1.5), (3, 0.5)]).unwrap();
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(ConstantEvaluator)
+            .spawn_distribution(spawn_distribution.clone())
+            .min_branch_proba(0.)
+            .build();
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 8, 0, 0,
+            0, 0, 4, 0,
+        ]);
+
+        // When
+        solver.start_search(2);
+        let value = solver.eval_average(board, 2, 1.0);
+
+        // Then
+        let total_proba: f32 = spawn_distribution.outcomes().iter().map(|(_, p)| p).sum();
+        assert!((1. - total_proba).abs() < 1e-6);
+        assert_eq!(
+            vec![(1, 0.8), (2, 0.15), (3, 0.05)],
+            spawn_distribution.outcomes()
+        );
+        // a constant evaluation is preserved as long as the probabilities sum to one
+        assert!((1. - value).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_invalid_spawn_distribution() {
+        // Given
+        let invalid_outcomes = vec![
+            vec![],
+            vec![(1, 0.), (2, 0.)],
+            vec![(1, 1.), (16, 1.)],
+            vec![(0, 1.)],
+            vec![(1, 2.), (2, -1.)],
+            vec![(1, f32::NAN)],
+            vec![(1, f32::INFINITY)],
+        ];
+
+        for outcomes in invalid_outcomes {
+            // When
+            let result = SpawnDistribution::new(outcomes);
+
+            // Then
+            assert_eq!(
+                Some(&ErrorKind::InvalidSpawnDistribution),
+                result.as_ref().err().map(|e| e.kind())
+            );
+        }
+    }
+
+    #[test]
+    fn test_depth_schedule() {
+        // Given
+        let solver = SolverBuilder::default().base_max_search_depth(2).build();
+        let custom_solver = SolverBuilder::default()
+            .base_max_search_depth(2)
+            .depth_schedule(DepthSchedule::new(vec![(2048, 1)], 7))
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            32, 64, 128, 256,
+            2048, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let depth = solver.compute_max_depth(board);
+        let custom_depth = custom_solver.compute_max_depth(board);
+
+        // Then
+        assert_eq!(5, depth);
+        assert_eq!(8, custom_depth);
+    }
+
+    #[test]
+    fn test_depth_schedule_with_empty_tiles_weight() {
+        // Given
+        let solver = SolverBuilder::default()
+            .base_max_search_depth(1)
+            .depth_schedule(DepthSchedule::default().with_empty_tiles_weight(0.5))
+            .build();
+
+        #[rustfmt::skip]
+        let empty_heavy_board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            32, 64, 128, 2048,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+        #[rustfmt::skip]
+        let crowded_board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            32, 64, 128, 2048,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+
+        // When
+        let empty_heavy_depth = solver.compute_max_depth(empty_heavy_board);
+        let crowded_depth = solver.compute_max_depth(crowded_board);
+
+        // Then
+        assert_eq!(4, empty_heavy_depth);
+        assert_eq!(8, crowded_depth);
+    }
+
+    #[test]
+    fn test_last_search_depth() {
+        // Given
+        let mut solver = SolverBuilder::default().base_max_search_depth(2).build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2048, 4, 0, 0,
+            2, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        solver.next_best_move(board);
+
+        // Then
+        assert_eq!(2, solver.last_search_depth());
+    }
+
+    #[test]
+    fn test_min_branch_proba_by_depth() {
+        // Given
+        let mut flat_solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .min_branch_proba(0.001)
+            .build();
+        let mut scaled_solver = SolverBuilder::default()
+            .board_evaluator(DummyEvaluator {})
+            .min_branch_proba_by_depth(|depth| if depth >= 2 { 0.001 } else { 0.05 })
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            4, 4, 0, 4,
+            16, 0, 0, 2,
+            0, 8, 0, 16,
+            0, 8, 0, 16,
+        ]);
+
+        // When
+        flat_solver.next_best_move(board);
+        scaled_solver.next_best_move(board);
+
+        // Then
+        let flat_nodes = flat_solver.last_search_stats().nodes;
+        let scaled_nodes = scaled_solver.last_search_stats().nodes;
+        assert!(scaled_nodes < flat_nodes);
+    }
+
+    #[test]
+    fn test_ordered_directions() {
+        assert_eq!(Direction::all(), &ordered_directions(None));
+        assert_eq!(
+            [
+                Direction::Up,
+                Direction::Left,
+                Direction::Right,
+                Direction::Down
+            ],
+            ordered_directions(Some(Direction::Up))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_next_best_move_timed() {
+        // Given
+        let mut solver = SolverBuilder::default().build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            0, 0, 2, 4,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let direction = solver.next_best_move_timed(board, Duration::from_nanos(1));
+
+        // Then
+        let direction = direction.unwrap();
+        assert_ne!(board, board.move_to(direction));
+    }
+
+    #[test]
+    fn test_next_best_move_with_symmetries() {
+        // Given
+        let mut solver = SolverBuilder::default()
+            .base_max_search_depth(2)
+            .use_symmetries(true)
+            .build();
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            0, 0, 0, 2,
+            0, 0, 2, 4,
+            0, 2, 4, 8,
+            0, 4, 8, 16,
+        ]);
+
+        // When
+        let direction = solver.next_best_move(board);
+
+        // Then
+        let mut reference_solver = SolverBuilder::default().base_max_search_depth(2).build();
+        assert_eq!(reference_solver.next_best_move(board), direction);
+    }
+
+    #[test]
+    fn test_try_next_best_move() {
+        // Given
+        struct NanEvaluator;
+        impl BoardEvaluator for NanEvaluator {
+            fn evaluate(&self, _board: Board) -> f32 {
+                f32::NAN
+            }
+
+            fn gameover_penalty(&self) -> f32 {
+                f32::NAN
+            }
+        }
+        let mut nan_solver = SolverBuilder::default()
+            .board_evaluator(NanEvaluator)
+            .base_max_search_depth(1)
+            .build();
+        let mut solver = SolverBuilder::default().base_max_search_depth(1).build();
+        // all the moves are possible, so that NaN evaluations are compared with each other
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 4, 0,
+        ]);
+        #[rustfmt::skip]
+        let game_over_board = Board::from(vec![
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+            2, 4, 2, 4,
+            4, 2, 4, 2,
+        ]);
+
+        // When
+        let nan_result = nan_solver.try_next_best_move(board);
+        let result = solver.try_next_best_move(board);
+        let game_over_result = solver.try_next_best_move(game_over_board);
+
+        // Then
+        assert_eq!(
+            Some(&ErrorKind::InvalidEvaluation),
+            nan_result.as_ref().err().map(|e| e.kind())
+        );
+        assert!(result.unwrap().is_some());
+        assert_eq!(Ok(None), game_over_result);
+    }
+
+    #[test]
+    fn test_nan_evaluation_is_never_chosen() {
+        // Given
+        struct LeftNanEvaluator;
+        impl BoardEvaluator for LeftNanEvaluator {
+            fn evaluate(&self, board: Board) -> f32 {
+                if board.get_value(4) != 0 {
+                    f32::NAN
+                } else {
+                    1.
+                }
+            }
+
+            fn gameover_penalty(&self) -> f32 {
+                0.
+            }
+        }
+        let mut solver = SolverBuilder::default()
+            .board_evaluator(LeftNanEvaluator)
+            .base_max_search_depth(0)
+            .build();
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let scored_move = solver.next_best_move_scored(board);
+
+        // Then
+        assert_eq!(0, solver.last_search_depth());
+        let (direction, score) = scored_move.unwrap();
+        assert_ne!(Direction::Left, direction);
+        assert_eq!(1., score);
+    }
+
+    #[test]
+    fn test_reset() {
+        // Given
+        let mut solver = SolverBuilder::default().base_max_search_depth(2).build();
+        let mut reference_solver = SolverBuilder::default().base_max_search_depth(2).build();
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            0, 0, 0, 0,
+            0, 2, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 4, 0,
+        ]);
+        solver.next_best_move(board);
+
+        // When
+        solver.reset();
+
+        // Then
+        assert_eq!(0, solver.table_len());
+        assert_eq!(0, solver.last_search_depth());
+        assert_eq!(SearchStats::default(), solver.last_search_stats());
+        assert_eq!(
+            reference_solver.next_best_move_scored(board),
+            solver.next_best_move_scored(board)
+        );
+        assert_eq!(
+            reference_solver.last_search_stats(),
+            solver.last_search_stats()
+        );
+    }
+
+    #[test]
+    fn test_scale_gameover_penalty() {
+        // Given
+        let build_solver = |scale_gameover_penalty| {
+            SolverBuilder::default()
+                .board_evaluator(ZeroEvaluator)
+                .base_max_search_depth(2)
+                .scale_gameover_penalty(scale_gameover_penalty)
+                .build()
+        };
+        // moving down loses after the next tile, while moving up loses one move later
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            8, 64, 16, 8,
+            16, 2, 4, 64,
+            64, 32, 64, 32,
+            16, 32, 16, 64,
+        ]);
+
+        // When
+        let flat_move = build_solver(false).next_best_move_scored(board);
+        let scaled_move = build_solver(true).next_best_move_scored(board);
+
+        // Then
+        assert_eq!(Some((Direction::Down, -1.)), flat_move);
+        assert_eq!(Direction::Up, scaled_move.unwrap().0);
+    }
+
+    #[test]
+    fn test_scale_gameover_penalty_with_persisted_table() {
+        // Given
+        let build_solver = || {
+            SolverBuilder::default()
+                .board_evaluator(ZeroEvaluator)
+                .base_max_search_depth(2)
+                .depth_schedule(DepthSchedule::new(vec![], 100))
+                .scale_gameover_penalty(true)
+                .build()
+        };
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            8, 64, 16, 8,
+            16, 2, 4, 64,
+            64, 32, 64, 32,
+            16, 32, 16, 64,
+        ]);
+        // this board is reached after moving up, and is searched deeper when it is the root
+        let next_board = board.move_to(Direction::Up).set_value(13, 2);
+        let mut solver = build_solver();
+
+        // When
+        solver.next_best_move_scored(next_board);
+        let scored_move = solver.next_best_move_scored(board);
+
+        // Then
+        assert_eq!(build_solver().next_best_move_scored(board), scored_move);
+    }
 }
@@ -0,0 +1,308 @@
+use crate::utils::{build_left_moves_table_with, build_right_moves_table_with, try_get_exponent};
+pub use crate::utils::{merge_equal_tiles, MergeRule};
+use alloc::vec::Vec;
+
+/// Mapping between the tile values of a game variant and the 4 bits slots in which a `Board`
+/// stores its tiles, along with the rule deciding which adjacent tiles merge
+///
+/// The slot `0` is always the empty tile. The methods of `Board` which do not take a scheme use
+/// the classic `PowerOfTwoScheme`.
+pub trait TileScheme {
+    /// Returns the slot of the tile value, or `None` if the value is not a valid tile value of
+    /// the scheme
+    fn try_value_to_slot(&self, value: u16) -> Option<u64>;
+
+    /// Returns the slot of the tile value
+    /// This panics when the value is not a valid tile value of the scheme.
+    fn value_to_slot(&self, value: u16) -> u64 {
+        self.try_value_to_slot(value)
+            .unwrap_or_else(|| panic!("Invalid tile value {}", value))
+    }
+
+    /// Returns the tile value stored in the slot
+    fn slot_to_value(&self, slot: u64) -> u16;
+
+    /// Returns the slot of the tile resulting from the merge of two adjacent non-empty tiles, or
+    /// `None` when they cannot be merged
+    /// By default, equal tiles merge into the next slot.
+    fn merge(&self, left_slot: u64, right_slot: u64) -> Option<u64> {
+        if left_slot == right_slot {
+            Some(left_slot + 1)
+        } else {
+            None
+        }
+    }
+}
+
+/// `TileScheme` of the classic game, in which slots are the exponents of powers of two
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PowerOfTwoScheme;
+
+impl TileScheme for PowerOfTwoScheme {
+    fn try_value_to_slot(&self, value: u16) -> Option<u64> {
+        try_get_exponent(value)
+    }
+
+    fn slot_to_value(&self, slot: u64) -> u16 {
+        match slot {
+            0 => 0,
+            slot => 1 << slot as u16,
+        }
+    }
+}
+
+/// Tables of the left and right moves of rows following a `TileScheme`, to be used with
+/// `Board::move_with_tables`
+pub struct MovesTables {
+    pub(crate) left: Vec<u16>,
+    pub(crate) right: Vec<u16>,
+}
+
+impl MovesTables {
+    /// Builds the moves tables of the scheme
+    pub fn new(scheme: &dyn TileScheme) -> Self {
+        let merge = |left: u8, right: u8| {
+            scheme
+                .merge(left as u64, right as u64)
+                .map(|slot| slot as u8)
+        };
+        Self::with_merge_rule(&merge)
+    }
+
+    /// Builds moves tables in which tiles are merged according to the `MergeRule`, which allows
+    /// merges of more than two tiles
+    pub fn with_merge_rule(merge_rule: &dyn MergeRule) -> Self {
+        Self {
+            left: build_left_moves_table_with(merge_rule),
+            right: build_right_moves_table_with(merge_rule),
+        }
+    }
+}
+
+impl Default for MovesTables {
+    fn default() -> Self {
+        Self::new(&PowerOfTwoScheme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{Board, Direction};
+    use crate::error::ErrorKind;
+
+    /// Fibonacci variant, in which two consecutive Fibonacci numbers merge into the next one
+    struct FibonacciScheme;
+
+    impl TileScheme for FibonacciScheme {
+        fn try_value_to_slot(&self, value: u16) -> Option<u64> {
+            (0..16).find(|slot| self.slot_to_value(*slot) == value)
+        }
+
+        fn slot_to_value(&self, slot: u64) -> u16 {
+            // slots 1, 2, 3, 4, ... contain 1, 2, 3, 5, ...
+            if slot == 0 {
+                return 0;
+            }
+            let (mut previous, mut current) = (1, 2);
+            for _ in 1..slot {
+                let next = previous + current;
+                previous = current;
+                current = next;
+            }
+            previous
+        }
+
+        fn merge(&self, left_slot: u64, right_slot: u64) -> Option<u64> {
+            let max_slot = left_slot.max(right_slot);
+            let min_slot = left_slot.min(right_slot);
+            if max_slot - min_slot == 1 || max_slot == 1 {
+                Some(max_slot + 1)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Powers of three variant, in which three equal tiles merge into the next power of three
+    struct PowersOfThreeScheme;
+
+    impl TileScheme for PowersOfThreeScheme {
+        fn try_value_to_slot(&self, value: u16) -> Option<u64> {
+            (0..16).find(|slot| self.slot_to_value(*slot) == value)
+        }
+
+        fn slot_to_value(&self, slot: u64) -> u16 {
+            match slot {
+                0 => 0,
+                slot => 2 * 3u16.pow(slot as u32 - 1),
+            }
+        }
+    }
+
+    struct TripleMergeRule;
+
+    impl MergeRule for TripleMergeRule {
+        fn merge(&self, tiles: &[u8]) -> Option<(u8, usize)> {
+            match tiles {
+                [first, second, third, ..] if first == second && second == third => {
+                    Some((first + 1, 3))
+                }
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_power_of_two_scheme() {
+        // Given
+        let scheme = PowerOfTwoScheme;
+
+        // When
+        let slots: Vec<_> = [0, 2, 2048]
+            .iter()
+            .map(|v| scheme.value_to_slot(*v))
+            .collect();
+        let values: Vec<_> = slots.iter().map(|s| scheme.slot_to_value(*s)).collect();
+
+        // Then
+        assert_eq!(vec![0, 1, 11], slots);
+        assert_eq!(vec![0, 2, 2048], values);
+        assert_eq!(None, scheme.try_value_to_slot(3));
+        assert_eq!(Some(12), scheme.merge(11, 11));
+        assert_eq!(None, scheme.merge(11, 10));
+    }
+
+    #[test]
+    fn test_merge_fibonacci_tiles() {
+        // Given
+        let scheme = FibonacciScheme;
+        let tables = MovesTables::new(&scheme);
+        #[rustfmt::skip]
+        let board = Board::from_values_in_scheme(&[
+            1, 1, 0, 0,
+            2, 3, 5, 0,
+            3, 0, 3, 0,
+            8, 5, 13, 21,
+        ], &scheme).unwrap();
+
+        // When
+        let moved_board = board.move_with_tables(Direction::Left, &tables);
+
+        // Then
+        #[rustfmt::skip]
+        let expected_values = vec![
+            2, 0, 0, 0,
+            5, 5, 0, 0,
+            3, 3, 0, 0,
+            13, 34, 0, 0,
+        ];
+        assert_eq!(expected_values, moved_board.values_in_scheme(&scheme));
+    }
+
+    #[test]
+    fn test_invalid_value_in_scheme() {
+        // Given
+        #[rustfmt::skip]
+        let values = [
+            1, 2, 3, 5,
+            8, 13, 21, 34,
+            0, 0, 0, 0,
+            0, 0, 4, 0,
+        ];
+
+        // When
+        let result = Board::from_values_in_scheme(&values, &FibonacciScheme);
+
+        // Then
+        assert_eq!(
+            &ErrorKind::InvalidSquareValue(4),
+            result.unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_slot_in_scheme() {
+        // Given
+        struct OffByOneScheme;
+
+        impl TileScheme for OffByOneScheme {
+            fn try_value_to_slot(&self, value: u16) -> Option<u64> {
+                Some(value as u64 + 1)
+            }
+
+            fn slot_to_value(&self, slot: u64) -> u16 {
+                slot as u16 - 1
+            }
+        }
+        let mut values = [0; 16];
+        values[3] = 15;
+
+        // When
+        let result = Board::from_values_in_scheme(&values, &OffByOneScheme);
+
+        // Then
+        assert_eq!(
+            &ErrorKind::InvalidSquareValue(15),
+            result.unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_default_moves_tables_match_board_moves() {
+        // Given
+        let tables = MovesTables::default();
+        #[rustfmt::skip]
+        let board = Board::from(vec![
+            2, 2, 0, 4,
+            0, 4, 4, 8,
+            16, 0, 16, 16,
+            2, 4, 8, 16,
+        ]);
+
+        for direction in Direction::all() {
+            // When
+            let moved_board = board.move_with_tables(*direction, &tables);
+
+            // Then
+            assert_eq!(board.move_to(*direction), moved_board);
+        }
+    }
+
+    #[test]
+    fn test_triple_merge_rule() {
+        // Given
+        let scheme = PowersOfThreeScheme;
+        let tables = MovesTables::with_merge_rule(&TripleMergeRule);
+        #[rustfmt::skip]
+        let values = [
+            2, 2, 2, 0,
+            6, 6, 0, 0,
+            2, 6, 6, 6,
+            0, 0, 0, 0,
+        ];
+        let board = Board::from_values_in_scheme(&values, &scheme).unwrap();
+        #[rustfmt::skip]
+        let classic_board = Board::from(vec![
+            2, 2, 2, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let moved_board = board.move_with_tables(Direction::Left, &tables);
+        let classic_moved_board = classic_board.move_to(Direction::Left);
+
+        // Then
+        #[rustfmt::skip]
+        let expected_values = vec![
+            6, 0, 0, 0,
+            6, 6, 0, 0,
+            2, 18, 0, 0,
+            0, 0, 0, 0,
+        ];
+        assert_eq!(expected_values, moved_board.values_in_scheme(&scheme));
+        assert_eq!(&[4, 2, 0, 0], &Vec::from(classic_moved_board)[..4]);
+    }
+}
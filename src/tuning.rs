@@ -0,0 +1,117 @@
+use crate::board::Board;
+use crate::solver::{GameResult, Solver};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
+
+/// Returns the candidate weights with which the solver performs best, along with its performance
+///
+/// For each candidate, a solver is built with `build_solver` and plays one game per seed,
+/// starting from `initial` and with 4 tiles being populated with probability `proba_4`. The
+/// candidates are compared by average score, ties being broken by average max tile. Games are
+/// played concurrently when the `parallel` feature is enabled, hence the `Sync` bound on
+/// `build_solver`. `None` is returned when there is no candidate or no seed.
+pub fn tune_weights<F>(
+    candidates: &[Vec<f32>],
+    seeds: &[u64],
+    initial: Board,
+    proba_4: f32,
+    build_solver: F,
+) -> Option<(Vec<f32>, TuningScore)>
+where
+    F: Fn(&[f32]) -> Solver + Sync,
+{
+    if seeds.is_empty() {
+        return None;
+    }
+    let play = |(candidate_idx, seed): (usize, u64)| {
+        let mut solver = build_solver(&candidates[candidate_idx]);
+        (candidate_idx, solver.play_out(initial, proba_4, seed))
+    };
+    let games: Vec<_> = (0..candidates.len())
+        .flat_map(|candidate_idx| seeds.iter().map(move |seed| (candidate_idx, *seed)))
+        .collect();
+    #[cfg(feature = "parallel")]
+    let results: Vec<_> = games.into_par_iter().map(play).collect();
+    #[cfg(not(feature = "parallel"))]
+    let results: Vec<_> = games.into_iter().map(play).collect();
+
+    (0..candidates.len())
+        .map(|candidate_idx| {
+            let candidate_results: Vec<&GameResult> = results
+                .iter()
+                .filter(|(idx, _)| *idx == candidate_idx)
+                .map(|(_, result)| result)
+                .collect();
+            (candidate_idx, TuningScore::new(&candidate_results))
+        })
+        .max_by(|(lhs_idx, lhs), (rhs_idx, rhs)| {
+            // the first candidate wins ties
+            lhs.partial_cmp(rhs)
+                .unwrap_or(Ordering::Equal)
+                .then(rhs_idx.cmp(lhs_idx))
+        })
+        .map(|(idx, score)| (candidates[idx].clone(), score))
+}
+
+/// Performance of a solver over several games
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct TuningScore {
+    pub average_score: f32,
+    pub average_max_tile: f32,
+}
+
+impl TuningScore {
+    fn new(results: &[&GameResult]) -> Self {
+        let nb_games = results.len() as f32;
+        Self {
+            average_score: results.iter().map(|r| r.score as f32).sum::<f32>() / nb_games,
+            average_max_tile: results.iter().map(|r| r.max_tile as f32).sum::<f32>() / nb_games,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluators::{
+        CombinedBoardEvaluator, EmptyTileEvaluator, PrecomputedBoardEvaluator,
+    };
+    use crate::solver::SolverBuilder;
+
+    #[test]
+    fn test_tune_weights() {
+        // Given
+        let candidates = vec![vec![-1.], vec![1.]];
+        let seeds = [1, 2];
+
+        #[rustfmt::skip]
+        let board: Board = Board::from(vec![
+            2, 4, 8, 16,
+            32, 64, 128, 256,
+            512, 2, 4, 8,
+            0, 0, 0, 0,
+        ]);
+
+        // When
+        let best = tune_weights(&candidates, &seeds, board, 0.1, |weights| {
+            let evaluator = CombinedBoardEvaluator::default()
+                .combine(EmptyTileEvaluator::default(), weights[0]);
+            SolverBuilder::default()
+                .board_evaluator(PrecomputedBoardEvaluator::new(evaluator))
+                .base_max_search_depth(1)
+                .min_branch_proba(0.1)
+                .build()
+        });
+
+        let no_game = tune_weights(&candidates, &[], board, 0.1, |_| {
+            SolverBuilder::default().build()
+        });
+
+        // Then
+        let (best_weights, score) = best.unwrap();
+        assert_eq!(vec![1.], best_weights);
+        assert!(score.average_score > 0.);
+        assert_eq!(None, no_game);
+    }
+}
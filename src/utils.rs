@@ -1,5 +1,12 @@
+use alloc::vec::Vec;
+
 pub fn get_exponent(value: u16) -> u64 {
-    match value {
+    try_get_exponent(value).unwrap_or_else(|| panic!("Invalid tile value {}", value))
+}
+
+/// Returns the exponent of the tile value, or `None` if the value is not a valid tile value
+pub fn try_get_exponent(value: u16) -> Option<u64> {
+    let exponent = match value {
         0 => 0,
         2 => 1,
         4 => 2,
@@ -16,42 +23,157 @@ pub fn get_exponent(value: u16) -> u64 {
         8192 => 13,
         16384 => 14,
         32768 => 15,
-        _ => panic!("Invalid tile value {}", value),
-    }
+        _ => return None,
+    };
+    Some(exponent)
 }
 
+/// Builds the table of left moves for rows of 4 tiles
+/// The table is only built at runtime with the `std` feature and without the `static-tables`
+/// one, otherwise it is generated by the build script.
+#[cfg_attr(any(feature = "static-tables", not(feature = "std")), allow(dead_code))]
 pub fn build_left_moves_table() -> Vec<u16> {
-    (0..(std::u16::MAX as usize + 1))
-        .map(|x| get_left_move(x as u16))
-        .collect()
+    build_left_moves_table_with(&merge_equal_tiles)
 }
 
+/// Builds the table of right moves for rows of 4 tiles, see `build_left_moves_table`
+#[cfg_attr(any(feature = "static-tables", not(feature = "std")), allow(dead_code))]
 pub fn build_right_moves_table() -> Vec<u16> {
-    (0..(std::u16::MAX as usize + 1))
-        .map(|x| get_right_move(x as u16))
+    build_right_moves_table_with(&merge_equal_tiles)
+}
+
+/// Builds the table of left moves for rows of 4 tiles, in which tiles are merged according to
+/// the `MergeRule`
+pub fn build_left_moves_table_with(merge_rule: &dyn MergeRule) -> Vec<u16> {
+    (0..(u16::MAX as usize + 1))
+        .map(|x| get_left_move(x as u16, merge_rule))
         .collect()
 }
 
-fn get_left_move(row: u16) -> u16 {
-    let mut result = row;
-    let mut prev_value = std::u8::MAX;
-    let mut new_value_idx = 0;
-    // whether or not tiles have been moved in this row
-    let mut moved = false;
+/// Builds the table of right moves for rows of 4 tiles, see `build_left_moves_table_with`
+pub fn build_right_moves_table_with(merge_rule: &dyn MergeRule) -> Vec<u16> {
+    (0..(u16::MAX as usize + 1))
+        .map(|x| get_right_move(x as u16, merge_rule))
+        .collect()
+}
+
+/// Rule deciding which tiles are merged when a row is moved
+/// The tiles are given as their 4 bits slots, `0` being the empty tile.
+pub trait MergeRule {
+    /// Returns the slot of the tile resulting from the merge of the first tiles of `tiles`,
+    /// along with the number of merged tiles, or `None` when the first tile is not merged
+    /// `tiles` contains the non-empty tiles of the row which have not been moved yet, in the
+    /// order of the move.
+    fn merge(&self, tiles: &[u8]) -> Option<(u8, usize)>;
+}
+
+/// Functions merging two adjacent tiles are merge rules
+impl<F: Fn(u8, u8) -> Option<u8>> MergeRule for F {
+    fn merge(&self, tiles: &[u8]) -> Option<(u8, usize)> {
+        match tiles {
+            [first, second, ..] => self(*first, *second).map(|merged| (merged, 2)),
+            _ => None,
+        }
+    }
+}
+
+/// Merge rule of the classic game, in which equal tiles merge into the next exponent
+pub fn merge_equal_tiles(left: u8, right: u8) -> Option<u8> {
+    if left == right {
+        Some(left + 1)
+    } else {
+        None
+    }
+}
+
+/// Builds the table of scores gained when moving each row. The score only depends on the tiles
+/// which are merged, which are the same whether the row is moved to the left or to the right.
+#[cfg_attr(any(feature = "static-tables", not(feature = "std")), allow(dead_code))]
+pub fn build_scores_table() -> Vec<u32> {
+    (0..(u16::MAX as usize + 1))
+        .map(|x| get_move_score(x as u16))
+        .collect()
+}
+
+/// Builds the table of the number of merges performed when moving each row, which is also the
+/// same in both directions
+#[cfg_attr(any(feature = "static-tables", not(feature = "std")), allow(dead_code))]
+pub fn build_merge_counts_table() -> Vec<u8> {
+    (0..(u16::MAX as usize + 1))
+        .map(|x| get_merge_count(x as u16))
+        .collect()
+}
+
+fn get_merge_count(row: u16) -> u8 {
+    let mut count = 0;
+    let mut prev_value = 0;
+    for i in 0..4 {
+        let value = (row >> (4 * (3 - i))) & 0xF;
+        if value == 0 {
+            continue;
+        }
+        if value == prev_value {
+            count += 1;
+            prev_value = 0;
+        } else {
+            prev_value = value;
+        }
+    }
+    count
+}
+
+fn get_move_score(row: u16) -> u32 {
+    let mut score = 0;
+    let mut prev_value = 0;
     for i in 0..4 {
-        let value: u8 = ((row & (0xF << (4 * (3 - i)))) >> (4 * (3 - i))) as u8;
+        let value = (row >> (4 * (3 - i))) & 0xF;
         if value == 0 {
-            moved = true;
-        } else if value == prev_value {
-            result = set_value_in_row(result, new_value_idx - 1, value + 1);
-            result = set_value_in_row(result, i as u8, 0);
-            prev_value = std::u8::MAX;
-            moved = true;
+            continue;
+        }
+        if value == prev_value {
+            score += 2 << value;
+            prev_value = 0;
         } else {
-            if moved {
-                result = set_value_in_row(result, new_value_idx, value);
-                result = set_value_in_row(result, i as u8, 0);
-            }
+            prev_value = value;
+        }
+    }
+    score
+}
+
+/// Builds the table of left moves for rows of `size` tiles, used by boards which are not 4x4
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub fn build_sized_left_moves_table(size: usize) -> Vec<u32> {
+    (0..(1 << (4 * size)))
+        .map(|x| get_sized_left_move(x as u32, size))
+        .collect()
+}
+
+/// Builds the table of right moves for rows of `size` tiles, used by boards which are not 4x4
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub fn build_sized_right_moves_table(size: usize) -> Vec<u32> {
+    (0..(1 << (4 * size)))
+        .map(|x| {
+            let inverted_row = invert_sized_row(x as u32, size);
+            invert_sized_row(get_sized_left_move(inverted_row, size), size)
+        })
+        .collect()
+}
+
+fn get_sized_left_move(row: u32, size: usize) -> u32 {
+    let mut result = 0;
+    let mut new_value_idx = 0;
+    let mut prev_value = 0;
+    for i in 0..size {
+        let value = (row >> (4 * (size - 1 - i))) & 0xF;
+        if value == 0 {
+            continue;
+        }
+        if value == prev_value {
+            // the previous tile has already been written at `new_value_idx - 1`
+            result += 1 << (4 * (size - new_value_idx));
+            prev_value = 0;
+        } else {
+            result |= value << (4 * (size - 1 - new_value_idx));
             prev_value = value;
             new_value_idx += 1;
         }
@@ -59,8 +181,36 @@ fn get_left_move(row: u16) -> u16 {
     result
 }
 
-fn get_right_move(row: u16) -> u16 {
-    invert_row(get_left_move(invert_row(row)))
+fn invert_sized_row(row: u32, size: usize) -> u32 {
+    (0..size).fold(0, |acc, i| (acc << 4) | ((row >> (4 * i)) & 0xF))
+}
+
+fn get_left_move(row: u16, merge_rule: &dyn MergeRule) -> u16 {
+    // the non empty tiles are packed in a stack buffer, as this runs for every row of the tables
+    let mut buffer = [0u8; 4];
+    let mut nb_tiles = 0;
+    for i in 0..4 {
+        let value = ((row >> (4 * (3 - i))) & 0xF) as u8;
+        if value != 0 {
+            buffer[nb_tiles] = value;
+            nb_tiles += 1;
+        }
+    }
+    let tiles = &buffer[..nb_tiles];
+    let mut result = 0;
+    let mut new_value_idx = 0;
+    let mut i = 0;
+    while i < tiles.len() {
+        let (value, nb_merged) = merge_rule.merge(&tiles[i..]).unwrap_or((tiles[i], 1));
+        result = set_value_in_row(result, new_value_idx, value);
+        new_value_idx += 1;
+        i += nb_merged.max(1);
+    }
+    result
+}
+
+fn get_right_move(row: u16, merge_rule: &dyn MergeRule) -> u16 {
+    invert_row(get_left_move(invert_row(row), merge_rule))
 }
 
 fn invert_row(row: u16) -> u16 {
@@ -101,7 +251,7 @@ mod tests {
         let row = 0b0101_0000_0101_1100;
 
         // When
-        let left_moved = get_left_move(row);
+        let left_moved = get_left_move(row, &merge_equal_tiles);
 
         // Then
         assert_eq!(0b0110_1100_0000_0000, left_moved);
@@ -113,12 +263,71 @@ mod tests {
         let row = 0b0101_0000_0101_1100;
 
         // When
-        let left_moved = get_right_move(row);
+        let left_moved = get_right_move(row, &merge_equal_tiles);
 
         // Then
         assert_eq!(0b0000_0000_0110_1100, left_moved);
     }
 
+    #[test]
+    fn should_get_move_score() {
+        // Given
+        let row = 0b0001_0001_0001_0001;
+
+        // When
+        let score = get_move_score(row);
+
+        // Then
+        assert_eq!(8, score);
+    }
+
+    #[test]
+    fn should_get_merge_count() {
+        // Given
+        let row = 0b0001_0001_0010_0010;
+
+        // When
+        let merge_count = get_merge_count(row);
+
+        // Then
+        assert_eq!(2, merge_count);
+    }
+
+    #[test]
+    fn should_get_sized_left_move() {
+        // Given
+        let row = 0b0001_0001_0001;
+
+        // When
+        let moved_row = get_sized_left_move(row, 3);
+
+        // Then
+        assert_eq!(0b0010_0001_0000, moved_row);
+    }
+
+    #[test]
+    fn should_match_left_move_for_size_four() {
+        // Given
+        let rows = [
+            0b0001_0001_0001_0001,
+            0b0000_0011_0010_0010,
+            0b0101_0000_0101_1100,
+        ];
+
+        // When
+        let moved_rows: Vec<_> = rows
+            .iter()
+            .map(|row| get_sized_left_move(*row as u32, 4))
+            .collect();
+
+        // Then
+        let expected_rows: Vec<_> = rows
+            .iter()
+            .map(|row| get_left_move(*row, &merge_equal_tiles) as u32)
+            .collect();
+        assert_eq!(expected_rows, moved_rows);
+    }
+
     #[test]
     fn should_invert_row() {
         // Given
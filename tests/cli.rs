@@ -0,0 +1,150 @@
+use play_2048::board::{Board, Direction};
+use play_2048::game::GameBuilder;
+use std::fs::write;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use std::str::FromStr;
+
+fn run_script(script: &str, args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_play-2048"))
+        .args(args)
+        .arg("--script")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_scripted_game() {
+    // Given
+    let script = "l d r u left";
+
+    // When
+    let output = run_script(script, &[]);
+
+    // Then
+    #[rustfmt::skip]
+    let board: Board = Board::from(vec![
+        0, 2, 0, 0,
+        0, 0, 0, 0,
+        0, 0, 0, 0,
+        0, 0, 0, 0,
+    ]);
+    let mut game = GameBuilder::default()
+        .initial_board(board)
+        .proba_4(0.1)
+        .seed(0)
+        .build();
+    game.populate_new_tile();
+    let moves = [
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+        Direction::Up,
+        Direction::Left,
+    ];
+    for direction in moves.iter() {
+        game.step(*direction);
+    }
+    let expected_output = format!("Seed: 0\n{:?}\nScore: {}\n", game.board, game.score);
+    assert!(output.status.success());
+    assert_eq!(expected_output, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_same_seed_yields_same_initial_tile() {
+    // Given
+    let seed_args = ["--seed", "42"];
+
+    // When
+    let first_output = run_script("", &seed_args);
+    let second_output = run_script("", &seed_args);
+
+    // Then
+    let first_stdout = String::from_utf8(first_output.stdout).unwrap();
+    assert!(first_output.status.success());
+    assert!(first_stdout.starts_with("Seed: 42\n"));
+    assert_eq!(
+        first_stdout,
+        String::from_utf8(second_output.stdout).unwrap()
+    );
+}
+
+fn write_board_file(name: &str, content: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_load_board_from_file() {
+    // Given
+    let path = write_board_file("board.txt", "2 . . . / . 4 . . / . . . . / . . . 2048\n");
+
+    // When
+    let output = run_script("", &["--board", path.to_str().unwrap()]);
+
+    // Then
+    #[rustfmt::skip]
+    let expected_board = Board::from(vec![
+        2, 0, 0, 0,
+        0, 4, 0, 0,
+        0, 0, 0, 0,
+        0, 0, 0, 2048,
+    ]);
+    let expected_output = format!("Seed: 0\n{:?}\nScore: 0\n", expected_board);
+    assert!(output.status.success());
+    assert_eq!(expected_output, String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_reject_malformed_board_file() {
+    // Given
+    let path = write_board_file("malformed_board.txt", "2 4 8 3");
+
+    // When
+    let output = run_script("", &["--board", path.to_str().unwrap()]);
+
+    // Then
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Invalid board file"));
+}
+
+#[test]
+fn test_bench_subcommand() {
+    // Given
+    let args = ["bench", "--games", "2", "-d", "0", "-m", "0.9"];
+
+    // When
+    let output = Command::new(env!("CARGO_BIN_EXE_play-2048"))
+        .args(args)
+        .output()
+        .unwrap();
+
+    // Then
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stats: Vec<(&str, f32)> = stdout
+        .lines()
+        .map(|line| {
+            let mut parts = line.split(": ");
+            let name = parts.next().unwrap();
+            (name, f32::from_str(parts.next().unwrap()).unwrap())
+        })
+        .collect();
+    assert_eq!(7, stats.len());
+    assert_eq!(("Games", 2.), stats[0]);
+    assert!(stats.iter().all(|(_, value)| value.is_finite()));
+}
@@ -0,0 +1,21 @@
+use play_2048::board::Board;
+use play_2048::solver::SolverBuilder;
+
+#[test]
+fn test_solver_is_available_without_cli_feature() {
+    // Given
+    #[rustfmt::skip]
+    let board = Board::from(vec![
+        2, 0, 0, 0,
+        2, 0, 0, 0,
+        0, 0, 0, 0,
+        0, 0, 0, 0,
+    ]);
+    let mut solver = SolverBuilder::default().build();
+
+    // When
+    let next_move = solver.next_best_move(board);
+
+    // Then
+    assert!(next_move.is_some());
+}